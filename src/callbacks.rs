@@ -2,6 +2,7 @@
 
 pub use ir::enum_ty::{EnumVariantCustomBehavior, EnumVariantValue};
 pub use ir::int::IntKind;
+use Diagnostic;
 use std::fmt;
 use std::panic::UnwindSafe;
 
@@ -21,6 +22,19 @@ impl Default for MacroParsingBehavior {
     }
 }
 
+/// The kind of an item being named, see `ParseCallbacks::item_name`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    /// A C++ namespace.
+    Module,
+    /// A type: a struct, union, enum, typedef...
+    Type,
+    /// A function or method.
+    Function,
+    /// A variable or constant.
+    Var,
+}
+
 /// A trait to allow configuring different kinds of types in different
 /// situations.
 pub trait ParseCallbacks: fmt::Debug + UnwindSafe {
@@ -35,6 +49,27 @@ pub trait ParseCallbacks: fmt::Debug + UnwindSafe {
         None
     }
 
+    /// This function will be run on every string macro. The value is the
+    /// string's bytes, without the terminating nul.
+    fn str_macro(&self, _name: &str, _value: &[u8]) {}
+
+    /// This function will be run on every function-like macro, which bindgen
+    /// doesn't otherwise generate anything for. `_params` are the names of
+    /// the macro's parameters, `...` for a variadic one, and `_tokens` are
+    /// the tokens of its body.
+    fn func_macro(&self, _name: &str, _params: &[&str], _tokens: &[&str]) {}
+
+    /// The integer kind a parameter of a translated function-like macro should
+    /// have, see `Builder::translate_function_macros`, or `None` to use the
+    /// kind inferred from the macro's body.
+    fn func_macro_param_type(
+        &self,
+        _macro_name: &str,
+        _param_name: &str,
+    ) -> Option<IntKind> {
+        None
+    }
+
     /// This function should return whether, given the a given enum variant
     /// name, and value, this enum variant will forcibly be a constant.
     fn enum_variant_behavior(
@@ -55,4 +90,45 @@ pub trait ParseCallbacks: fmt::Debug + UnwindSafe {
     ) -> Option<String> {
         None
     }
+
+    /// Allows to rename an item, replacing `_original_item_name`, which is
+    /// the name bindgen would otherwise have chosen. Functions and variables
+    /// keep linking against their original symbol.
+    fn item_name(
+        &self,
+        _original_item_name: &str,
+        _kind: ItemKind,
+    ) -> Option<String> {
+        None
+    }
+
+    /// This function will be run on every diagnostic clang reports while
+    /// parsing the input headers, before bindgen decides whether to fail.
+    ///
+    /// Without parse callbacks, the diagnostics are printed to stderr instead.
+    fn diagnostic(&self, _diagnostic: &Diagnostic) {}
+
+    /// This function will be run on every file clang reads while parsing:
+    /// the input headers, and then each file reached through an `#include`.
+    fn include_file(&self, _filename: &str) {}
+}
+
+/// A `ParseCallbacks` implementation for build scripts, which tells Cargo to
+/// rerun the build script whenever one of the headers the bindings were
+/// generated from changes.
+///
+/// ```ignore
+/// let bindings = bindgen::builder()
+///     .header("wrapper.h")
+///     .parse_callbacks(Box::new(bindgen::CargoCallbacks))
+///     .generate()
+///     .expect("Unable to generate bindings");
+/// ```
+#[derive(Debug)]
+pub struct CargoCallbacks;
+
+impl ParseCallbacks for CargoCallbacks {
+    fn include_file(&self, filename: &str) {
+        println!("cargo:rerun-if-changed={}", filename);
+    }
 }
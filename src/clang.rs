@@ -103,6 +103,13 @@ impl Cursor {
         }
     }
 
+    /// Given that this cursor points to an `#include` directive, get the path
+    /// of the included file.
+    pub fn get_included_file_name(&self) -> Option<String> {
+        let file = unsafe { clang_getIncludedFile(self.x) };
+        File { x: file }.name()
+    }
+
     /// Returns whether the cursor refers to a built-in definition.
     pub fn is_builtin(&self) -> bool {
         let (file, _, _, _) = self.location().location();
@@ -460,6 +467,12 @@ impl Cursor {
             unsafe { clang_Cursor_isFunctionInlined(self.x) != 0 }
     }
 
+    /// Is the referent a function-like macro definition?
+    pub fn is_macro_function_like(&self) -> bool {
+        clang_Cursor_isMacroFunctionLike::is_loaded() &&
+            unsafe { clang_Cursor_isMacroFunctionLike(self.x) != 0 }
+    }
+
     /// Get the width of this cursor's referent bit field, or `None` if the
     /// referent is not a bit field.
     pub fn bit_width(&self) -> Option<u32> {
@@ -634,6 +647,42 @@ impl Cursor {
         if rt.is_valid() { Some(rt) } else { None }
     }
 
+    /// Does this cursor have the given simple attribute, like
+    /// `warn_unused_result`?
+    ///
+    /// Newer libclang versions expose the attribute as a cursor of kind
+    /// `exposed_kind`. Older ones expose most attributes as unexposed ones,
+    /// so we look at their tokens.
+    pub fn has_simple_attr(
+        &self,
+        attr: &str,
+        exposed_kind: CXCursorKind,
+    ) -> bool {
+        let mut found_attr = false;
+        self.visit(|cur| {
+            if cur.kind() == exposed_kind {
+                found_attr = true;
+                return CXChildVisit_Break;
+            }
+
+            if cur.kind() == CXCursor_UnexposedAttr {
+                found_attr = cur.tokens().map_or(false, |tokens| {
+                    tokens.iter().any(|t| {
+                        t.kind == CXToken_Identifier && t.spelling == attr
+                    })
+                });
+
+                if found_attr {
+                    return CXChildVisit_Break;
+                }
+            }
+
+            CXChildVisit_Continue
+        });
+
+        found_attr
+    }
+
     /// Gets the tokens that correspond to that cursor.
     pub fn tokens(&self) -> Option<Vec<Token>> {
         let range = self.extent();
@@ -647,9 +696,26 @@ impl Cursor {
                 return None;
             }
 
+            let end = SourceLocation {
+                x: clang_getRangeEnd(range),
+            }.location().3;
+
             let token_array =
                 slice::from_raw_parts(token_ptr, num_tokens as usize);
             for &token in token_array.iter() {
+                // libclang versions previous to 4.0 return an extra token
+                // past the end of macro definitions, skip it.
+                //
+                // See:
+                //   https://bugs.llvm.org//show_bug.cgi?id=9069
+                //   https://reviews.llvm.org/D26446
+                let start = SourceLocation {
+                    x: clang_getTokenLocation(tu, token),
+                }.location().3;
+                if start > end {
+                    continue;
+                }
+
                 let kind = clang_getTokenKind(token);
                 let spelling =
                     cxstring_into_string(clang_getTokenSpelling(tu, token));
@@ -854,6 +920,36 @@ impl Type {
         s
     }
 
+    /// If this is a C11 `_Atomic` type, get the canonical spelling of the type
+    /// it makes atomic, like `int` for `_Atomic(my_int_t)`.
+    ///
+    /// clang-sys doesn't bind `clang_Type_getValueType` yet, so this goes
+    /// through the spelling of the canonical type.
+    pub fn atomic_value_spelling(&self) -> Option<String> {
+        if !self.is_maybe_atomic() {
+            return None;
+        }
+        atomic_value(&self.canonical_type().spelling())
+    }
+
+    /// Like `atomic_value_spelling`, but with the type spelled as written,
+    /// like `size_t` for `_Atomic(size_t)`.
+    pub fn atomic_value_written_spelling(&self) -> Option<String> {
+        if !self.is_maybe_atomic() {
+            return None;
+        }
+        atomic_value(&self.spelling())
+    }
+
+    /// Could this be a C11 `_Atomic` type?
+    fn is_maybe_atomic(&self) -> bool {
+        // clang-sys doesn't know about `CXType_Atomic` yet, and libclang
+        // reports atomic types as unexposed before 11.0.
+        #[allow(non_upper_case_globals)]
+        const CXType_Atomic: CXTypeKind = 177;
+        self.kind() == CXType_Atomic || self.kind() == CXType_Unexposed
+    }
+
     /// Is this type const qualified?
     pub fn is_const(&self) -> bool {
         unsafe { clang_isConstQualifiedType(self.x) != 0 }
@@ -1068,6 +1164,29 @@ impl Type {
     }
 }
 
+/// Get the type an atomic type makes atomic, given the atomic type's spelling,
+/// like `int` for `const _Atomic(int)`.
+fn atomic_value(spelling: &str) -> Option<String> {
+    let mut value = spelling.trim();
+    loop {
+        let unqualified = value
+            .trim_start_matches("const ")
+            .trim_start_matches("volatile ")
+            .trim_end_matches(" const")
+            .trim_end_matches(" volatile");
+        if unqualified == value {
+            break;
+        }
+        value = unqualified;
+    }
+
+    if value.starts_with("_Atomic(") && value.ends_with(')') {
+        Some(value["_Atomic(".len()..value.len() - 1].trim().to_owned())
+    } else {
+        None
+    }
+}
+
 /// The `CanonicalTypeDeclaration` type exists as proof-by-construction that its
 /// cursor is the canonical declaration for its type. If you have a
 /// `CanonicalTypeDeclaration` instance, you know for sure that the type and
@@ -1162,6 +1281,12 @@ impl fmt::Display for SourceLocation {
     }
 }
 
+impl fmt::Debug for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
 /// A comment in the source text.
 ///
 /// Comments are sort of parsed by Clang, and have a tree structure.
@@ -1442,6 +1567,21 @@ impl Diagnostic {
     pub fn severity(&self) -> CXDiagnosticSeverity {
         unsafe { clang_getDiagnosticSeverity(self.x) }
     }
+
+    /// Get the text of this diagnostic message, without any location or
+    /// severity information.
+    pub fn spelling(&self) -> String {
+        unsafe { cxstring_into_string(clang_getDiagnosticSpelling(self.x)) }
+    }
+
+    /// Get the source location this diagnostic message refers to.
+    pub fn location(&self) -> SourceLocation {
+        unsafe {
+            SourceLocation {
+                x: clang_getDiagnosticLocation(self.x),
+            }
+        }
+    }
 }
 
 impl Drop for Diagnostic {
@@ -1459,7 +1599,8 @@ pub struct UnsavedFile {
     /// The name of the unsaved file. Kept here to avoid leaving dangling pointers in
     /// `CXUnsavedFile`.
     pub name: CString,
-    contents: CString,
+    /// The contents of the unsaved file.
+    pub contents: CString,
 }
 
 impl UnsavedFile {
@@ -0,0 +1,150 @@
+//! Code generation for loading a shared library at runtime, instead of linking
+//! against it.
+
+use super::utils;
+use ir::context::BindgenContext;
+use ir::function::{Abi, FunctionSig};
+use proc_macro2::{Literal, Term};
+use quote;
+
+/// The functions collected while generating bindings for the dynamic library,
+/// and the pieces of the library struct they need.
+#[derive(Default)]
+pub struct DynamicItems {
+    /// The struct fields, one `Result` of a function pointer per function.
+    ///
+    /// ```ignore
+    /// pub struct Lib {
+    ///     __library: ::libloading::Library,
+    ///     pub foo: Result<unsafe extern "C" fn(x: i32), ::libloading::Error>,
+    ///     ...
+    /// }
+    /// ```
+    struct_members: Vec<quote::Tokens>,
+
+    /// The convenience methods calling through the loaded function pointers.
+    ///
+    /// ```ignore
+    /// impl Lib {
+    ///     ...
+    ///     pub unsafe fn foo(&self, x: i32) {
+    ///         (self.foo.as_ref().expect("..."))(x)
+    ///     }
+    /// }
+    /// ```
+    struct_implementation: Vec<quote::Tokens>,
+
+    /// The symbol lookups performed when loading the library.
+    ///
+    /// ```ignore
+    /// let foo = __library.get(b"foo\0").map(|sym| *sym);
+    /// ```
+    constructor_inits: Vec<quote::Tokens>,
+
+    /// The field initializers of the library struct.
+    init_fields: Vec<quote::Tokens>,
+}
+
+impl DynamicItems {
+    /// Merge the functions collected in `other`, e.g. in a nested module, into
+    /// this set.
+    pub fn extend(&mut self, other: DynamicItems) {
+        self.struct_members.extend(other.struct_members);
+        self.struct_implementation.extend(other.struct_implementation);
+        self.constructor_inits.extend(other.constructor_inits);
+        self.init_fields.extend(other.init_fields);
+    }
+
+    /// Generate the library struct named `lib_ident`, with every function
+    /// collected so far.
+    pub fn get_tokens(
+        &self,
+        ctx: &BindgenContext,
+        lib_ident: Term,
+    ) -> quote::Tokens {
+        let prefix = ctx.trait_prefix();
+        let struct_members = &self.struct_members;
+        let struct_implementation = &self.struct_implementation;
+        let constructor_inits = &self.constructor_inits;
+        let init_fields = &self.init_fields;
+
+        quote! {
+            extern crate libloading;
+
+            pub struct #lib_ident {
+                __library: ::libloading::Library,
+                #( #struct_members )*
+            }
+
+            impl #lib_ident {
+                pub unsafe fn new<P>(
+                    path: P
+                ) -> ::#prefix::result::Result<Self, ::libloading::Error>
+                where P: AsRef<::#prefix::ffi::OsStr> {
+                    let library = ::libloading::Library::new(path)?;
+                    Self::from_library(library)
+                }
+
+                pub unsafe fn from_library<L>(
+                    library: L
+                ) -> ::#prefix::result::Result<Self, ::libloading::Error>
+                where L: Into<::libloading::Library> {
+                    let __library = library.into();
+                    #( #constructor_inits )*
+                    Ok(#lib_ident {
+                        __library: __library,
+                        #( #init_fields ),*
+                    })
+                }
+
+                #( #struct_implementation )*
+            }
+        }
+    }
+
+    /// Add a function with the given signature to the library struct, looking
+    /// it up as `symbol`.
+    pub fn push(
+        &mut self,
+        ctx: &BindgenContext,
+        ident: Term,
+        symbol: &str,
+        abi: Abi,
+        signature: &FunctionSig,
+    ) {
+        let prefix = ctx.trait_prefix();
+        let args = &utils::fnsig_arguments(ctx, signature);
+        let args_identifiers =
+            utils::fnsig_argument_identifiers(ctx, signature);
+        let ret = &utils::fnsig_return_ty(ctx, signature);
+        let fn_ty = quote! {
+            unsafe extern #abi fn ( #( #args ),* ) #ret
+        };
+
+        self.struct_members.push(quote! {
+            pub #ident: ::#prefix::result::Result<#fn_ty, ::libloading::Error>,
+        });
+
+        // We can't forward variadic arguments, those have to be called through
+        // the field directly.
+        if !signature.is_variadic() {
+            self.struct_implementation.push(quote! {
+                pub unsafe fn #ident ( &self, #( #args ),* ) #ret {
+                    let sym = self.#ident.as_ref().expect(
+                        "Expected function, got error."
+                    );
+                    (sym)( #( #args_identifiers ),* )
+                }
+            });
+        }
+
+        let symbol = Literal::byte_string(format!("{}\0", symbol).as_bytes());
+        self.constructor_inits.push(quote! {
+            let #ident = __library.get(#symbol).map(|sym| *sym);
+        });
+
+        self.init_fields.push(quote! {
+            #ident: #ident
+        });
+    }
+}
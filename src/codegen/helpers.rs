@@ -31,6 +31,18 @@ pub mod attributes {
         }
     }
 
+    pub fn non_exhaustive() -> quote::Tokens {
+        quote! {
+            #[non_exhaustive]
+        }
+    }
+
+    pub fn must_use() -> quote::Tokens {
+        quote! {
+            #[must_use]
+        }
+    }
+
     pub fn inline() -> quote::Tokens {
         quote! {
             #[inline]
@@ -100,6 +112,16 @@ pub fn integer_type(layout: Layout) -> Option<quote::Tokens> {
     }
 }
 
+/// Strips the `r#` off a mangled name, so other identifiers can be derived
+/// from it.
+pub fn unraw(name: &str) -> &str {
+    if name.starts_with("r#") {
+        &name[2..]
+    } else {
+        name
+    }
+}
+
 /// Generates a bitfield allocation unit type for a type with the given `Layout`.
 pub fn bitfield_unit(ctx: &BindgenContext, layout: Layout) -> quote::Tokens {
     let mut tokens = quote! {};
@@ -126,6 +148,7 @@ pub fn bitfield_unit(ctx: &BindgenContext, layout: Layout) -> quote::Tokens {
 pub mod ast_ty {
     use ir::context::BindgenContext;
     use ir::function::FunctionSig;
+    use ir::layout::Layout;
     use ir::ty::FloatKind;
     use quote;
     use proc_macro2;
@@ -139,6 +162,10 @@ pub mod ast_ty {
                     #prefix::#ident
                 }
             }
+            None if ctx.options().use_core &&
+                ctx.options().rust_features().core_ffi_c => quote! {
+                ::core::ffi::#ident
+            },
             None => quote! {
                 ::std::os::raw::#ident
             },
@@ -148,22 +175,50 @@ pub mod ast_ty {
     pub fn float_kind_rust_type(
         ctx: &BindgenContext,
         fk: FloatKind,
+        layout: Option<Layout>,
     ) -> quote::Tokens {
-        // TODO: we probably should just take the type layout into
-        // account?
-        //
-        // Also, maybe this one shouldn't be the default?
+        // TODO: maybe this one shouldn't be the default?
         //
         // FIXME: `c_longdouble` doesn't seem to be defined in some
-        // systems, so we use `c_double` directly.
+        // systems, so we use `c_double` or the layout directly.
+        let features = ctx.options().rust_features();
         match (fk, ctx.options().convert_floats) {
+            (FloatKind::Float16, _) => {
+                if features.f16 {
+                    return quote! { f16 };
+                }
+                ctx.generated_bindgen_float16();
+                if ctx.options().enable_cxx_namespaces {
+                    quote! { root::__BindgenFloat16 }
+                } else {
+                    quote! { __BindgenFloat16 }
+                }
+            }
             (FloatKind::Float, true) => quote! { f32 },
-            (FloatKind::Double, true) |
-            (FloatKind::LongDouble, true) => quote! { f64 },
+            (FloatKind::Double, true) => quote! { f64 },
             (FloatKind::Float, false) => raw_type(ctx, "c_float"),
-            (FloatKind::Double, false) |
-            (FloatKind::LongDouble, false) => raw_type(ctx, "c_double"),
-            (FloatKind::Float128, _) => quote! { [u8; 16] },
+            (FloatKind::Double, false) => raw_type(ctx, "c_double"),
+            // Rust has no extended precision floats, so unless `long double`
+            // is just a `double`, like on Windows, keep its bits in something
+            // with the same layout.
+            (FloatKind::LongDouble, convert_floats) => match layout {
+                Some(layout) if layout.size != 8 => {
+                    if layout.size == 16 && features.i128_and_u128 {
+                        quote! { u128 }
+                    } else {
+                        super::blob(layout)
+                    }
+                }
+                _ if convert_floats => quote! { f64 },
+                _ => raw_type(ctx, "c_double"),
+            },
+            (FloatKind::Float128, _) => {
+                if features.i128_and_u128 {
+                    quote! { u128 }
+                } else {
+                    quote! { [u8; 16] }
+                }
+            }
         }
     }
 
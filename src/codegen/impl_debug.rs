@@ -155,6 +155,7 @@ impl<'a> ImplDebug<'a> for Item {
             TypeKind::Enum(..) |
             TypeKind::Reference(..) |
             TypeKind::BlockPointer |
+            TypeKind::Atomic(..) |
             TypeKind::UnresolvedTypeRef(..) |
             TypeKind::ObjCInterface(..) |
             TypeKind::ObjCId |
@@ -174,6 +175,14 @@ impl<'a> ImplDebug<'a> for Item {
                 Some((format!("{}: Non-debuggable generic", name), vec![]))
             }
 
+            TypeKind::Vector(_, count) => {
+                if count <= RUST_DERIVE_IN_ARRAY_LIMIT {
+                    debug_print(name, quote! { #name_ident })
+                } else {
+                    Some((format!("{}: Vector with length {}", name, count), vec![]))
+                }
+            }
+
             TypeKind::Array(_, len) => {
                 // Generics are not required to implement Debug
                 if self.has_type_param_in_array(ctx) {
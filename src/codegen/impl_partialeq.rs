@@ -96,8 +96,17 @@ fn gen_field(ctx: &BindgenContext, ty_item: &Item, name: &str) -> quote::Tokens
         TypeKind::Comp(..) |
         TypeKind::Pointer(_) |
         TypeKind::Function(..) |
+        TypeKind::Vector(..) |
         TypeKind::Opaque => quote_equals(name_ident),
 
+        TypeKind::Atomic(..) => {
+            let prefix = ctx.trait_prefix();
+            quote! {
+                self.#name_ident.load(::#prefix::sync::atomic::Ordering::Relaxed) ==
+                    other.#name_ident.load(::#prefix::sync::atomic::Ordering::Relaxed)
+            }
+        }
+
         TypeKind::TemplateInstantiation(ref inst) => {
             if inst.is_opaque(ctx, &ty_item) {
                 quote! {
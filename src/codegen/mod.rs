@@ -1,3 +1,4 @@
+mod dyngen;
 mod impl_debug;
 mod impl_partialeq;
 mod error;
@@ -10,6 +11,7 @@ pub(crate) mod bitfield_unit;
 #[cfg(test)]
 mod bitfield_unit_tests;
 
+use self::dyngen::DynamicItems;
 use self::helpers::attributes;
 use self::struct_layout::StructLayoutTracker;
 
@@ -18,9 +20,9 @@ use super::BindgenOptions;
 use ir::analysis::{HasVtable, Sizedness};
 use ir::annotations::FieldAccessorKind;
 use ir::comment;
-use ir::comp::{Base, Bitfield, BitfieldUnit, CompInfo, CompKind, Field,
+use ir::comp::{Bitfield, BitfieldUnit, CompInfo, CompKind, Field,
                FieldData, FieldMethods, Method, MethodKind};
-use ir::context::{BindgenContext, ItemId};
+use ir::context::{BindgenContext, ItemId, TypeId};
 use ir::derive::{CanDeriveCopy, CanDeriveDebug, CanDeriveDefault,
                  CanDeriveHash, CanDerivePartialOrd, CanDeriveOrd,
                  CanDerivePartialEq, CanDeriveEq, CanDerive};
@@ -34,8 +36,8 @@ use ir::layout::Layout;
 use ir::module::Module;
 use ir::objc::{ObjCInterface, ObjCMethod};
 use ir::template::{AsTemplateParam, TemplateInstantiation, TemplateParameters};
-use ir::ty::{Type, TypeKind};
-use ir::var::Var;
+use ir::ty::{AtomicKind, Type, TypeKind};
+use ir::var::{FunctionMacro, MacroToken, Var};
 
 use quote;
 use proc_macro2::{self, Term, Span};
@@ -105,6 +107,10 @@ struct CodegenResult<'a> {
     /// Whether a bitfield allocation unit has been seen at least once.
     saw_bitfield_unit: bool,
 
+    /// Whether a `TryFrom` impl for a Rust enum has been generated at least
+    /// once.
+    saw_enum_try_from: bool,
+
     items_seen: HashSet<ItemId>,
     /// The set of generated function/var names, needed because in C/C++ is
     /// legal to do something like:
@@ -129,6 +135,17 @@ struct CodegenResult<'a> {
     /// function name to the number of overloads we have already codegen'd for
     /// that name. This lets us give each overload a unique suffix.
     overload_counters: HashMap<String, u32>,
+
+    /// The functions to load at runtime, when generating bindings for a
+    /// dynamically loaded library.
+    dynamic_items: DynamicItems,
+
+    /// The C definitions of the exported wrappers for static and inline
+    /// functions.
+    static_wrappers: Vec<String>,
+
+    /// The C `_Static_assert`s checking the layouts bindgen computed.
+    c_layout_assertions: Vec<String>,
 }
 
 impl<'a> CodegenResult<'a> {
@@ -140,11 +157,15 @@ impl<'a> CodegenResult<'a> {
             saw_incomplete_array: false,
             saw_objc: false,
             saw_bitfield_unit: false,
+            saw_enum_try_from: false,
             codegen_id: codegen_id,
             items_seen: Default::default(),
             functions_seen: Default::default(),
             vars_seen: Default::default(),
             overload_counters: Default::default(),
+            dynamic_items: Default::default(),
+            static_wrappers: vec![],
+            c_layout_assertions: vec![],
         }
     }
 
@@ -169,6 +190,10 @@ impl<'a> CodegenResult<'a> {
         self.saw_bitfield_unit = true;
     }
 
+    fn saw_enum_try_from(&mut self) {
+        self.saw_enum_try_from = true;
+    }
+
     fn seen<Id: Into<ItemId>>(&self, item: Id) -> bool {
         self.items_seen.contains(&item.into())
     }
@@ -215,6 +240,10 @@ impl<'a> CodegenResult<'a> {
         self.saw_incomplete_array |= new.saw_incomplete_array;
         self.saw_objc |= new.saw_objc;
         self.saw_bitfield_unit |= new.saw_bitfield_unit;
+        self.saw_enum_try_from |= new.saw_enum_try_from;
+        self.dynamic_items.extend(new.dynamic_items);
+        self.static_wrappers.extend(new.static_wrappers);
+        self.c_layout_assertions.extend(new.c_layout_assertions);
 
         new.items
     }
@@ -288,6 +317,7 @@ impl AppendImplicitTemplateParams for quote::Tokens {
             TypeKind::Float(..) |
             TypeKind::Complex(..) |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
             TypeKind::TypeParam |
             TypeKind::Opaque |
             TypeKind::Function(..) |
@@ -393,6 +423,12 @@ impl CodeGenerator for Module {
             }
 
             if item.id() == ctx.root_module() {
+                if let Some(ref lib_name) = ctx.options().dynamic_library_name {
+                    let lib_ident = ctx.rust_ident(lib_name);
+                    let dynamic_items_tokens =
+                        result.dynamic_items.get_tokens(ctx, lib_ident);
+                    result.push(dynamic_items_tokens);
+                }
                 if result.saw_bindgen_union {
                     utils::prepend_union_types(ctx, &mut *result);
                 }
@@ -402,12 +438,18 @@ impl CodeGenerator for Module {
                 if ctx.need_bindegen_complex_type() {
                     utils::prepend_complex_type(&mut *result);
                 }
+                if ctx.need_bindgen_float16_type() {
+                    utils::prepend_float16_type(ctx, &mut *result);
+                }
                 if result.saw_objc {
                     utils::prepend_objc_header(ctx, &mut *result);
                 }
                 if result.saw_bitfield_unit {
                     utils::prepend_bitfield_unit_type(&mut *result);
                 }
+                if result.saw_enum_try_from {
+                    utils::prepend_unknown_enum_value_type(&mut *result);
+                }
             }
         };
 
@@ -554,6 +596,23 @@ impl CodeGenerator for Var {
                         pub const #canonical_ident : #ty = #c ;
                     });
                 }
+                VarType::FunctionMacro(ref function_macro) => {
+                    match function_macro_codegen(
+                        ctx,
+                        function_macro,
+                        canonical_ident,
+                        ty,
+                    ) {
+                        Some(tokens) => result.push(tokens),
+                        None => {
+                            warn!(
+                                "Skipping function-like macro {}, its \
+                                 translation isn't valid Rust",
+                                canonical_name
+                            );
+                        }
+                    }
+                }
             }
         } else {
             let mut attrs = vec![];
@@ -563,8 +622,9 @@ impl CodeGenerator for Var {
                 attrs.push(attributes::link_name(self.name()));
             }
 
+            let maybe_unsafe = utils::extern_block_unsafety(ctx);
             let mut tokens = quote!(
-                extern "C" {
+                #maybe_unsafe extern "C" {
                     #(#attrs)*
                     pub static mut #canonical_ident: #ty;
                 }
@@ -575,6 +635,244 @@ impl CodeGenerator for Var {
     }
 }
 
+/// Generate a `const fn` for a translated function-like macro, or a
+/// `macro_rules!` if the target doesn't support `const fn`.
+///
+/// Returns `None` if the translation turns out not to be valid Rust.
+fn function_macro_codegen(
+    ctx: &BindgenContext,
+    function_macro: &FunctionMacro,
+    ident: Term,
+    ty: quote::Tokens,
+) -> Option<quote::Tokens> {
+    let params = function_macro.params();
+    let features = ctx.options().rust_features();
+
+    // C wraps around on unsigned overflow, while Rust panics in debug builds
+    // and refuses to evaluate constants, so use the wrapping methods.
+    let wrapping = !function_macro.kind().is_signed();
+    let uses_wrapping_ops = wrapping &&
+        function_macro.body().iter().any(|token| match *token {
+            MacroToken::Raw(ref raw) => {
+                raw == "+" || raw == "-" || raw == "*" || raw == "<<"
+            }
+            MacroToken::Param(..) => false,
+        });
+    let ty_name = ty.to_string().replace(' ', "");
+
+    // The body isn't necessarily made of balanced token trees on its own, so
+    // we build it as source and parse it back.
+    let body = |param: &Fn(usize) -> String| -> Option<String> {
+        let tokens = function_macro
+            .body()
+            .iter()
+            .map(|token| match *token {
+                MacroToken::Param(i) => param(i),
+                MacroToken::Raw(ref raw) => raw.clone(),
+            })
+            .collect::<Vec<_>>();
+        if wrapping {
+            WrappingExpr::rewrite(&tokens, &ty_name)
+        } else {
+            Some(tokens.join(" "))
+        }
+    };
+
+    if !features.min_const_fn ||
+        (uses_wrapping_ops && !features.const_wrapping_ops)
+    {
+        let matcher = params
+            .iter()
+            .map(|&(ref name, _, _)| format!("${}:expr", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let matcher: proc_macro2::TokenStream = matcher.parse().ok()?;
+        // The wrapping methods need to know the type they're called on.
+        let body = body(&|i| if uses_wrapping_ops {
+            format!("(${} as {})", params[i].0, ty_name)
+        } else {
+            format!("${}", params[i].0)
+        })?;
+        let body: proc_macro2::TokenStream = body.parse().ok()?;
+
+        // Both the arguments and the expansion are expressions on their own,
+        // so there are no precedence issues to care about.
+        return Some(quote! {
+            #[macro_export]
+            macro_rules! #ident {
+                ( #matcher ) => { #body };
+            }
+        });
+    }
+
+    let param_names = params
+        .iter()
+        .map(|&(ref name, _, _)| ctx.rust_ident(name))
+        .collect::<Vec<_>>();
+    let param_tys = params
+        .iter()
+        .map(|&(_, _, param_ty)| param_ty.to_rust_ty_or_opaque(ctx, &()));
+    let body = body(&|i| {
+        let name = &param_names[i];
+        if params[i].1 == function_macro.kind() {
+            name.to_string()
+        } else {
+            format!("({} as {})", name, ty_name)
+        }
+    })?;
+    let body: proc_macro2::TokenStream = body.parse().ok()?;
+    let param_names = &param_names;
+
+    Some(quote! {
+        pub const fn #ident ( #( #param_names : #param_tys ),* ) -> #ty {
+            #body
+        }
+    })
+}
+
+/// Rewrites the translated body of a function-like macro computed in an
+/// unsigned type, so that `+`, `-`, `*` and `<<` wrap around like in C.
+struct WrappingExpr<'a> {
+    tokens: &'a [String],
+    pos: usize,
+    ty: &'a str,
+}
+
+impl<'a> WrappingExpr<'a> {
+    /// Rewrite the given tokens, or return `None` if they aren't an
+    /// expression we understand.
+    fn rewrite(tokens: &'a [String], ty: &'a str) -> Option<String> {
+        let mut expr = WrappingExpr {
+            tokens: tokens,
+            pos: 0,
+            ty: ty,
+        };
+        let result = expr.binary(0)?;
+        if expr.pos == tokens.len() {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// The precedence of a binary operator, the higher the tighter.
+    fn precedence(op: &str) -> Option<u8> {
+        Some(match op {
+            "|" => 1,
+            "^" => 2,
+            "&" => 3,
+            "<<" | ">>" => 4,
+            "+" | "-" => 5,
+            "*" | "/" | "%" => 6,
+            _ => return None,
+        })
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.tokens.get(self.pos)?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn binary(&mut self, min_precedence: u8) -> Option<String> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.tokens.get(self.pos) {
+            let precedence = match Self::precedence(op) {
+                Some(p) if p >= min_precedence => p,
+                _ => break,
+            };
+            self.pos += 1;
+            let rhs = self.binary(precedence + 1)?;
+            let method = match &**op {
+                "+" => "wrapping_add",
+                "-" => "wrapping_sub",
+                "*" => "wrapping_mul",
+                "<<" => {
+                    let amount = match Self::literal(&rhs) {
+                        Some(literal) => literal.to_owned(),
+                        None => format!("({}) as u32", rhs),
+                    };
+                    lhs = format!(
+                        "{}.wrapping_shl({})",
+                        self.receiver(&lhs),
+                        amount
+                    );
+                    continue;
+                }
+                _ => {
+                    lhs = format!("{} {} {}", lhs, op, rhs);
+                    continue;
+                }
+            };
+            lhs = format!("{}.{}({})", self.receiver(&lhs), method, rhs);
+        }
+        Some(lhs)
+    }
+
+    fn unary(&mut self) -> Option<String> {
+        match self.next()? {
+            op @ "!" | op @ "-" => Some(format!("{}{}", op, self.unary()?)),
+            "(" => {
+                let inner = self.binary(0)?;
+                match self.next()? {
+                    ")" => Some(format!("({})", inner)),
+                    _ => None,
+                }
+            }
+            token if token == ")" || Self::precedence(token).is_some() => {
+                None
+            }
+            token => Some(token.to_owned()),
+        }
+    }
+
+    /// Make an expression suitable to call a method on. Integer literals get
+    /// a suffix, since their type can't be inferred from a method call.
+    fn receiver(&self, expr: &str) -> String {
+        if let Some(literal) = Self::literal(expr) {
+            return format!("{}{}", literal, self.ty);
+        }
+
+        let is_simple = expr.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if is_simple || Self::is_grouped(expr) {
+            expr.to_owned()
+        } else {
+            format!("({})", expr)
+        }
+    }
+
+    /// The integer literal the expression is, if any, without its parens.
+    fn literal(expr: &str) -> Option<&str> {
+        let literal = expr.trim_start_matches('(').trim_end_matches(')');
+        if literal.parse::<u64>().is_ok() {
+            Some(literal)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the expression is wholly between a pair of parens.
+    fn is_grouped(expr: &str) -> bool {
+        if !expr.starts_with('(') {
+            return false;
+        }
+        let mut depth = 0;
+        for (i, c) in expr.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return i == expr.len() - 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+}
+
 impl CodeGenerator for Type {
     type Extra = Item;
 
@@ -594,6 +892,8 @@ impl CodeGenerator for Type {
             TypeKind::Float(..) |
             TypeKind::Complex(..) |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
+            TypeKind::Atomic(..) |
             TypeKind::Pointer(..) |
             TypeKind::BlockPointer |
             TypeKind::Reference(..) |
@@ -639,6 +939,14 @@ impl CodeGenerator for Type {
                     return;
                 }
 
+                if let TypeKind::Vector(elem, count) =
+                    *inner_item.expect_type().kind()
+                {
+                    if vector_alias_codegen(ctx, item, self, elem, count, result) {
+                        return;
+                    }
+                }
+
                 let mut outer_params = item.used_template_params(ctx);
 
                 let inner_rust_type = if item.is_opaque(ctx, &()) {
@@ -758,25 +1066,190 @@ impl CodeGenerator for Type {
     }
 }
 
+/// Generate a type of its own for a vector typedef, like `__m128` or
+/// `typedef int v4si __attribute__((vector_size(16)))`, so it has the vector's
+/// alignment.
+///
+/// Returns false if the target can't do better than an alias to an array of
+/// the elements.
+fn vector_alias_codegen(
+    ctx: &BindgenContext,
+    item: &Item,
+    ty: &Type,
+    elem: TypeId,
+    count: usize,
+    result: &mut CodegenResult,
+) -> bool {
+    let rust_name = ctx.rust_ident(&item.canonical_name(ctx));
+    let spelling = ty.name().expect("Unnamed alias?");
+
+    let mut attributes = vec![];
+    if let Some(comment) = item.comment(ctx) {
+        attributes.push(attributes::doc(comment));
+    }
+
+    // Prefer the `core::arch` types, so that the bindings work with the
+    // intrinsics and other crates.
+    if let Some(arch_ty) = utils::arch_vector_type(ctx, spelling) {
+        result.push(quote! {
+            #( #attributes )*
+            pub type #rust_name = #arch_ty;
+        });
+        return true;
+    }
+
+    let layout = match ty.layout(ctx) {
+        Some(layout) => layout,
+        None => return false,
+    };
+    if ctx.options().rust_features().repr_simd {
+        attributes.push(attributes::repr("simd"));
+    } else if ctx.options().rust_features().repr_align {
+        let align = helpers::ast_ty::int_expr(layout.align as i64);
+        attributes.push(attributes::repr("C"));
+        attributes.push(quote! {
+            #[repr(align(#align))]
+        });
+    } else {
+        return false;
+    }
+
+    let mut derives = vec![];
+    if item.can_derive_debug(ctx) {
+        derives.push("Debug");
+    }
+    if item.can_derive_default(ctx) {
+        derives.push("Default");
+    }
+    if item.can_derive_copy(ctx) {
+        derives.extend_from_slice(&["Copy", "Clone"]);
+    }
+    if item.can_derive_hash(ctx) {
+        derives.push("Hash");
+    }
+    if item.can_derive_partialord(ctx) {
+        derives.push("PartialOrd");
+    }
+    if item.can_derive_ord(ctx) {
+        derives.push("Ord");
+    }
+    if item.can_derive_partialeq(ctx) {
+        derives.push("PartialEq");
+    }
+    if item.can_derive_eq(ctx) {
+        derives.push("Eq");
+    }
+    if !derives.is_empty() {
+        attributes.push(attributes::derives(&derives));
+    }
+
+    let elem = elem.to_rust_ty_or_opaque(ctx, &());
+    result.push(quote! {
+        #( #attributes )*
+        pub struct #rust_name(pub [#elem; #count]);
+    });
+    true
+}
+
 struct Vtable<'a> {
     item_id: ItemId,
-    #[allow(dead_code)]
-    methods: &'a [Method],
-    #[allow(dead_code)]
-    base_classes: &'a [Base],
+    comp_info: &'a CompInfo,
 }
 
 impl<'a> Vtable<'a> {
-    fn new(
-        item_id: ItemId,
-        methods: &'a [Method],
-        base_classes: &'a [Base],
-    ) -> Self {
+    fn new(item_id: ItemId, comp_info: &'a CompInfo) -> Self {
         Vtable {
             item_id: item_id,
-            methods: methods,
-            base_classes: base_classes,
+            comp_info: comp_info,
+        }
+    }
+
+    /// Generate a function pointer field for each virtual method, in
+    /// declaration order, if we know the layout of the vtable.
+    ///
+    /// We don't know where the destructors go, since we don't know where they
+    /// were declared, nor how virtual bases are laid out, so we only handle
+    /// classes without either of them.
+    ///
+    /// This is only called for classes holding their own vtable pointer. A
+    /// derived class sharing its base's pointer gets no vtable at all, since
+    /// we can't tell its overrides from the virtual methods it adds.
+    fn typed_fields(&self, ctx: &BindgenContext) -> Option<Vec<quote::Tokens>> {
+        if self.comp_info.base_members().iter().any(|base| base.is_virtual()) {
+            return None;
+        }
+
+        // The methods of templates aren't kept, and any virtual method we
+        // failed to parse would shift the slots after it.
+        if !self.comp_info.self_template_params(ctx).is_empty() {
+            return None;
+        }
+
+        let kept = self.comp_info
+            .methods()
+            .iter()
+            .filter(|method| method.is_virtual())
+            .count();
+        if kept != self.comp_info.virtual_method_count() {
+            return None;
+        }
+
+        match self.comp_info.destructor() {
+            Some((MethodKind::VirtualDestructor { .. }, _)) => return None,
+            _ => {}
+        }
+
+        let class_ident = ctx.rust_ident(self.item_id.canonical_name(ctx));
+        let mut method_names = HashMap::new();
+        let mut fields = vec![];
+
+        for method in self.comp_info.methods() {
+            if !method.is_virtual() {
+                continue;
+            }
+
+            let function_item = ctx.resolve_item(method.signature());
+            let function = function_item.expect_function();
+            let signature_item = ctx.resolve_item(function.signature());
+            let signature = match *signature_item.expect_type().kind() {
+                TypeKind::Function(ref sig) => sig,
+                _ => panic!("How in the world?"),
+            };
+
+            let abi = match signature.abi() {
+                Abi::ThisCall if !ctx.options().rust_features().thiscall_abi => {
+                    return None;
+                }
+                Abi::Unknown(..) => return None,
+                abi => abi,
+            };
+
+            // Overloads are told apart the same way methods are.
+            let mut name = function.name().to_owned();
+            let count = {
+                let count = method_names.entry(name.clone()).or_insert(0);
+                *count += 1;
+                *count - 1
+            };
+            if count != 0 {
+                name.push_str(&count.to_string());
+            }
+            let name = ctx.rust_ident(&name);
+
+            let mut args = utils::fnsig_arguments(ctx, signature);
+            let ret = utils::fnsig_return_ty(ctx, signature);
+            args[0] = if method.is_const() {
+                quote! { this: *const #class_ident }
+            } else {
+                quote! { this: *mut #class_ident }
+            };
+
+            fields.push(quote! {
+                pub #name: unsafe extern #abi fn( #( #args ),* ) #ret,
+            });
         }
+
+        Some(fields)
     }
 }
 
@@ -792,9 +1265,25 @@ impl<'a> CodeGenerator for Vtable<'a> {
         assert_eq!(item.id(), self.item_id);
         debug_assert!(item.is_enabled_for_codegen(ctx));
 
-        // For now, generate an empty struct, later we should generate function
-        // pointers and whatnot.
         let name = ctx.rust_ident(&self.canonical_name(ctx));
+
+        if ctx.options().vtable_generation {
+            if let Some(fields) = self.typed_fields(ctx) {
+                result.push(quote! {
+                    #[repr(C)]
+                    pub struct #name {
+                        #( #fields )*
+                    }
+                });
+                return;
+            }
+
+            warn!(
+                "Generating an opaque vtable for {}, its layout is unknown",
+                self.item_id.canonical_name(ctx)
+            );
+        }
+
         let void = helpers::ast_ty::raw_type(ctx, "c_void");
         result.push(quote! {
             #[repr(C)]
@@ -877,15 +1366,35 @@ impl CodeGenerator for TemplateInstantiation {
                 ::#prefix::mem::align_of::<#ident>()
             };
 
-            let item = quote! {
-                #[test]
-                fn #fn_name() {
-                    assert_eq!(#size_of_expr, #size,
-                               concat!("Size of template specialization: ",
-                                       stringify!(#ident)));
-                    assert_eq!(#align_of_expr, #align,
-                               concat!("Alignment of template specialization: ",
-                                       stringify!(#ident)));
+            let item = if ctx.options().compile_time_layout_tests &&
+                ctx.options().rust_features().const_panic
+            {
+                let ty_name = ident.to_string();
+                let size_msg = format!(
+                    "Size of template specialization: {}",
+                    ty_name
+                );
+                let align_msg = format!(
+                    "Alignment of template specialization: {}",
+                    ty_name
+                );
+                quote! {
+                    const _: () = {
+                        assert!(#size_of_expr == #size, #size_msg);
+                        assert!(#align_of_expr == #align, #align_msg);
+                    };
+                }
+            } else {
+                quote! {
+                    #[test]
+                    fn #fn_name() {
+                        assert_eq!(#size_of_expr, #size,
+                                   concat!("Size of template specialization: ",
+                                           stringify!(#ident)));
+                        assert_eq!(#align_of_expr, #align,
+                                   concat!("Alignment of template specialization: ",
+                                           stringify!(#ident)));
+                    }
                 }
             };
 
@@ -967,6 +1476,23 @@ impl<'a> FieldCodegen<'a> for Field {
     }
 }
 
+/// Whether the Rust type generated for `ty` is `Copy`. Structs and unions only
+/// are when they derive it, while the other types are whenever they can be,
+/// regardless of `--no-derive-copy`.
+fn is_rust_copy(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty.into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+    match *item.expect_type().kind() {
+        TypeKind::Comp(..) | TypeKind::TemplateInstantiation(..) => {
+            item.can_derive_copy(ctx) && !item.annotations().disallow_copy()
+        }
+        TypeKind::Array(inner, _) => is_rust_copy(ctx, inner),
+        _ => ctx.lookup_can_derive_copy(item.id()),
+    }
+}
+
 impl<'a> FieldCodegen<'a> for FieldData {
     type Extra = ();
 
@@ -1024,6 +1550,18 @@ impl<'a> FieldCodegen<'a> for FieldData {
             ty
         };
 
+        // Rust unions may only hold non-`Copy` fields through `ManuallyDrop`.
+        let ty = if parent.is_union() && parent.can_be_rust_union(ctx) &&
+            !is_rust_copy(ctx, self.ty())
+        {
+            let prefix = ctx.trait_prefix();
+            quote! {
+                ::#prefix::mem::ManuallyDrop<#ty>
+            }
+        } else {
+            ty
+        };
+
         let mut field = quote! {};
         if ctx.options().generate_comments {
             if let Some(raw_comment) = self.comment() {
@@ -1071,9 +1609,12 @@ impl<'a> FieldCodegen<'a> for FieldData {
             return;
         }
 
-        let getter_name = ctx.rust_ident_raw(format!("get_{}", field_name));
-        let mutable_getter_name =
-            ctx.rust_ident_raw(format!("get_{}_mut", field_name));
+        let getter_name = ctx.rust_ident_raw(
+            format!("get_{}", helpers::unraw(&field_name)),
+        );
+        let mutable_getter_name = ctx.rust_ident_raw(
+            format!("get_{}_mut", helpers::unraw(&field_name)),
+        );
         let field_name = ctx.rust_ident_raw(field_name);
 
         methods.extend(Some(match accessor_kind {
@@ -1440,8 +1981,7 @@ impl CodeGenerator for CompInfo {
 
         if !is_opaque {
             if item.has_vtable_ptr(ctx) {
-                let vtable =
-                    Vtable::new(item.id(), self.methods(), self.base_members());
+                let vtable = Vtable::new(item.id(), self);
                 vtable.codegen(ctx, result, item);
 
                 let vtable_type = vtable
@@ -1770,19 +2310,9 @@ impl CodeGenerator for CompInfo {
                     let size = layout.size;
                     let align = layout.align;
 
-                    let check_struct_align =
-                        if align > ctx.target_pointer_size() &&
-                            !ctx.options().rust_features().repr_align
-                        {
-                            None
-                        } else {
-                            Some(quote! {
-                                assert_eq!(#align_of_expr,
-                                       #align,
-                                       concat!("Alignment of ", stringify!(#canonical_ident)));
-
-                            })
-                        };
+                    let should_check_struct_align =
+                        align <= ctx.target_pointer_size() ||
+                            ctx.options().rust_features().repr_align;
 
                     // FIXME when [issue #465](https://github.com/rust-lang-nursery/rust-bindgen/issues/465) ready
                     let too_many_base_vtables = self.base_members()
@@ -1793,53 +2323,174 @@ impl CodeGenerator for CompInfo {
                     let should_skip_field_offset_checks = is_opaque ||
                         too_many_base_vtables;
 
-                    let check_field_offset =
-                        if should_skip_field_offset_checks {
-                            vec![]
-                        } else {
-                            let asserts = self.fields()
-                                .iter()
-                                .filter_map(|field| match *field {
-                                    Field::DataMember(ref f) if f.name().is_some() => Some(f),
-                                    _ => None,
-                                })
-                                .flat_map(|field| {
-                                    let name = field.name().unwrap();
-                                    field.offset().and_then(|offset| {
-                                        let field_offset = offset / 8;
-                                        let field_name = ctx.rust_ident(name);
-
-                                        Some(quote! {
-                                            assert_eq!(
-                                                unsafe {
-                                                    &(*(::#prefix::ptr::null::<#canonical_ident>())).#field_name as *const _ as usize
-                                                },
-                                                #field_offset,
-                                                concat!("Offset of field: ", stringify!(#canonical_ident), "::", stringify!(#field_name))
-                                            );
-                                        })
-                                    })
+                    let field_offsets = if should_skip_field_offset_checks {
+                        vec![]
+                    } else {
+                        self.fields()
+                            .iter()
+                            .filter_map(|field| match *field {
+                                Field::DataMember(ref f) if f.name().is_some() => Some(f),
+                                _ => None,
+                            })
+                            .filter_map(|field| {
+                                let name = field.name().unwrap();
+                                field.offset().map(|offset| {
+                                    (ctx.rust_ident(name), offset / 8)
                                 })
-                                .collect::<Vec<quote::Tokens>>();
+                            })
+                            .collect::<Vec<_>>()
+                    };
+
+                    // Computing offsets through a null pointer is undefined
+                    // behavior, so use `offset_of!`, or `addr_of!` on an
+                    // uninitialized value, where the target has them.
+                    let features = ctx.options().rust_features();
 
-                            asserts
+                    let check_field_offset = field_offsets
+                        .iter()
+                        .map(|&(ref field_name, field_offset)| {
+                            let field_offset_expr = if features.offset_of {
+                                quote! {
+                                    ::#prefix::mem::offset_of!(#canonical_ident, #field_name)
+                                }
+                            } else if features.raw_ref_macros {
+                                quote! {
+                                    unsafe {
+                                        ::#prefix::ptr::addr_of!((*ptr).#field_name) as usize - ptr as usize
+                                    }
+                                }
+                            } else {
+                                quote! {
+                                    unsafe {
+                                        &(*(::#prefix::ptr::null::<#canonical_ident>())).#field_name as *const _ as usize
+                                    }
+                                }
+                            };
+
+                            quote! {
+                                assert_eq!(
+                                    #field_offset_expr,
+                                    #field_offset,
+                                    concat!("Offset of field: ", stringify!(#canonical_ident), "::", stringify!(#field_name))
+                                );
+                            }
+                        })
+                        .collect::<Vec<quote::Tokens>>();
+
+                    let uninit_decl = if !check_field_offset.is_empty() &&
+                        !features.offset_of &&
+                        features.raw_ref_macros
+                    {
+                        Some(quote! {
+                            const UNINIT: ::#prefix::mem::MaybeUninit<#canonical_ident> =
+                                ::#prefix::mem::MaybeUninit::uninit();
+                            let ptr = UNINIT.as_ptr();
+                        })
+                    } else {
+                        None
+                    };
+
+                    let item = if ctx.options().compile_time_layout_tests &&
+                        features.const_panic
+                    {
+                        let mut asserts = vec![];
+                        let size_msg =
+                            format!("Size of: {}", canonical_ident.as_str());
+                        asserts.push(quote! {
+                            assert!(#size_of_expr == #size, #size_msg);
+                        });
+
+                        if should_check_struct_align {
+                            let align_msg = format!(
+                                "Alignment of {}",
+                                canonical_ident.as_str()
+                            );
+                            asserts.push(quote! {
+                                assert!(#align_of_expr == #align, #align_msg);
+                            });
+                        }
+
+                        // Field offsets can't be computed at compile time
+                        // without `offset_of!`, so those are still checked
+                        // by a test.
+                        let offset_test = if features.offset_of {
+                            for &(ref field_name, field_offset) in &field_offsets {
+                                let offset_msg = format!(
+                                    "Offset of field: {}::{}",
+                                    canonical_ident.as_str(),
+                                    field_name.as_str()
+                                );
+                                asserts.push(quote! {
+                                    assert!(
+                                        ::#prefix::mem::offset_of!(#canonical_ident, #field_name) == #field_offset,
+                                        #offset_msg
+                                    );
+                                });
+                            }
+                            None
+                        } else if !check_field_offset.is_empty() {
+                            Some(quote! {
+                                #[test]
+                                fn #fn_name() {
+                                    #uninit_decl
+                                    #( #check_field_offset )*
+                                }
+                            })
+                        } else {
+                            None
                         };
 
-                    let item = quote! {
-                        #[test]
-                        fn #fn_name() {
-                            assert_eq!(#size_of_expr,
-                                       #size,
-                                       concat!("Size of: ", stringify!(#canonical_ident)));
+                        quote! {
+                            const _: () = {
+                                #( #asserts )*
+                            };
+                            #offset_test
+                        }
+                    } else {
+                        let check_struct_align = if should_check_struct_align {
+                            Some(quote! {
+                                assert_eq!(#align_of_expr,
+                                       #align,
+                                       concat!("Alignment of ", stringify!(#canonical_ident)));
+
+                            })
+                        } else {
+                            None
+                        };
 
-                            #check_struct_align
-                            #( #check_field_offset )*
+                        quote! {
+                            #[test]
+                            fn #fn_name() {
+                                #uninit_decl
+                                assert_eq!(#size_of_expr,
+                                           #size,
+                                           concat!("Size of: ", stringify!(#canonical_ident)));
+
+                                #check_struct_align
+                                #( #check_field_offset )*
+                            }
                         }
                     };
                     result.push(item);
                 }
             }
 
+            if ctx.options().c_layout_assertions.is_some() &&
+                !self.is_forward_declaration()
+            {
+                if let (Some(layout), Some(spelling)) =
+                    (layout, self.c_spelling())
+                {
+                    c_layout_assertions_codegen(
+                        self,
+                        spelling,
+                        layout,
+                        is_opaque,
+                        result,
+                    );
+                }
+            }
+
             let mut method_names = Default::default();
             if ctx.options().codegen_config.methods {
                 for method in self.methods() {
@@ -1951,7 +2602,181 @@ impl CodeGenerator for CompInfo {
                 }
             });
         }
+
+        if ctx.options().owned_wrappers && !is_opaque &&
+            generic_param_names.is_empty()
+        {
+            owned_wrapper_codegen(ctx, self, &canonical_name, result);
+        }
+    }
+}
+
+/// Generate the C `_Static_assert`s checking that the C compiler agrees with
+/// the size, alignment and field offsets bindgen computed for a struct or
+/// union.
+fn c_layout_assertions_codegen<'a>(
+    comp_info: &CompInfo,
+    spelling: &str,
+    layout: Layout,
+    is_opaque: bool,
+    result: &mut CodegenResult<'a>,
+) {
+    let mut assertions = vec![];
+    assertions.push(format!(
+        "_Static_assert(sizeof({0}) == {1}, \"Size of {0}\");",
+        spelling,
+        layout.size
+    ));
+    assertions.push(format!(
+        "_Static_assert(_Alignof({0}) == {1}, \"Alignment of {0}\");",
+        spelling,
+        layout.align
+    ));
+
+    let fields = if is_opaque { &[][..] } else { comp_info.fields() };
+    for field in fields {
+        let field = match *field {
+            Field::DataMember(ref field) => field,
+            Field::Bitfields(_) => continue,
+        };
+        if let (Some(name), Some(offset)) = (field.name(), field.offset()) {
+            assertions.push(format!(
+                "_Static_assert(offsetof({0}, {1}) == {2}, \
+                 \"Offset of field {1} in {0}\");",
+                spelling,
+                name,
+                offset / 8
+            ));
+        }
+    }
+
+    result.c_layout_assertions.push(assertions.join("\n"));
+}
+
+/// Generate a wrapper owning an instance of a C++ class with a destructor,
+/// which runs the destructor when dropped, along with constructors returning
+/// it.
+fn owned_wrapper_codegen<'a>(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    canonical_name: &str,
+    result: &mut CodegenResult<'a>,
+) {
+    let cc = &ctx.options().codegen_config;
+    if !cc.destructors {
+        return;
+    }
+
+    let destructor = match comp_info.destructor() {
+        // Pure virtual destructors have no symbol to call.
+        Some((kind, _)) if kind.is_pure_virtual() => return,
+        Some((_, destructor)) => destructor,
+        None => return,
+    };
+
+    let destructor_item = ctx.resolve_item(destructor);
+    if destructor_item.is_blacklisted(ctx) {
+        return;
+    }
+    destructor_item.codegen(ctx, result, &());
+    let destructor_name =
+        ctx.rust_ident(destructor_item.canonical_name(ctx));
+
+    let prefix = ctx.trait_prefix();
+    let ident = ctx.rust_ident(canonical_name);
+    let owned_ident = ctx.rust_ident(format!("{}_Owned", canonical_name));
+
+    let mut constructors = vec![];
+    if cc.constructors {
+        for &constructor in comp_info.constructors() {
+            let function_item = ctx.resolve_item(constructor);
+            let function = function_item.expect_function();
+            let signature_item = ctx.resolve_item(function.signature());
+            let signature = match *signature_item.expect_type().kind() {
+                TypeKind::Function(ref sig) => sig,
+                _ => panic!("How in the world?"),
+            };
+
+            // Same as for the constructor methods.
+            if function_item.is_blacklisted(ctx) || signature.is_variadic() {
+                continue;
+            }
+            if let (Abi::ThisCall, false) =
+                (signature.abi(), ctx.options().rust_features().thiscall_abi)
+            {
+                continue;
+            }
+
+            function_item.codegen(ctx, result, &());
+            let function_name =
+                ctx.rust_ident(function_item.canonical_name(ctx));
+
+            let mut args = utils::fnsig_arguments(ctx, signature);
+            args.remove(0);
+            let mut exprs =
+                helpers::ast_ty::arguments_from_signature(&signature, ctx);
+            exprs.remove(0);
+
+            let name = if constructors.is_empty() {
+                ctx.rust_ident("new")
+            } else {
+                ctx.rust_ident(format!("new{}", constructors.len()))
+            };
+
+            let (tmp_variable_decl, tmp_variable_ptr, tmp_variable_value) =
+                utils::constructor_tmp_variable(ctx);
+            constructors.push(quote! {
+                #[inline]
+                pub unsafe fn #name ( #( #args ),* ) -> Self {
+                    #tmp_variable_decl;
+                    #function_name(#tmp_variable_ptr, #( #exprs ),* );
+                    #owned_ident(#tmp_variable_value)
+                }
+            });
+        }
+    }
+
+    let doc = attributes::doc(format!(
+        "/// An owned `{}`, which is destroyed when dropped.",
+        canonical_name
+    ));
+    result.push(quote! {
+        #doc
+        pub struct #owned_ident(pub #ident);
+    });
+
+    if !constructors.is_empty() {
+        result.push(quote! {
+            impl #owned_ident {
+                #( #constructors )*
+            }
+        });
     }
+
+    result.push(quote! {
+        impl ::#prefix::ops::Deref for #owned_ident {
+            type Target = #ident;
+
+            #[inline]
+            fn deref(&self) -> &#ident {
+                &self.0
+            }
+        }
+
+        impl ::#prefix::ops::DerefMut for #owned_ident {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut #ident {
+                &mut self.0
+            }
+        }
+
+        impl Drop for #owned_ident {
+            #[inline]
+            fn drop(&mut self) {
+                unsafe { #destructor_name(&mut self.0) }
+            }
+        }
+    });
 }
 
 trait MethodCodegen {
@@ -2057,15 +2882,10 @@ impl MethodCodegen for Method {
         // If it's a constructor, we need to insert an extra parameter with a
         // variable called `__bindgen_tmp` we're going to create.
         if self.is_constructor() {
-            let prefix = ctx.trait_prefix();
-            let tmp_variable_decl =
-                quote! {
-                    let mut __bindgen_tmp = ::#prefix::mem::uninitialized()
-                };
+            let (tmp_variable_decl, tmp_variable_ptr, _) =
+                utils::constructor_tmp_variable(ctx);
             stmts.push(tmp_variable_decl);
-            exprs[0] = quote! {
-                &mut __bindgen_tmp
-            };
+            exprs[0] = tmp_variable_ptr;
         } else if !self.is_static() {
             assert!(!exprs.is_empty());
             exprs[0] = quote! {
@@ -2080,9 +2900,9 @@ impl MethodCodegen for Method {
         stmts.push(call);
 
         if self.is_constructor() {
-            stmts.push(quote! {
-                __bindgen_tmp
-            });
+            let (_, _, tmp_variable_value) =
+                utils::constructor_tmp_variable(ctx);
+            stmts.push(tmp_variable_value);
         }
 
         let block = quote! {
@@ -2105,8 +2925,8 @@ impl MethodCodegen for Method {
 /// A helper type that represents different enum variations.
 #[derive(Copy, Clone)]
 enum EnumVariation {
-    Rust,
-    Bitfield,
+    Rust { non_exhaustive: bool },
+    NewType { is_bitfield: bool },
     Consts,
     ModuleConsts
 }
@@ -2114,14 +2934,16 @@ enum EnumVariation {
 impl EnumVariation {
     fn is_rust(&self) -> bool {
         match *self {
-            EnumVariation::Rust => true,
+            EnumVariation::Rust { .. } => true,
             _ => false
         }
     }
 
-    fn is_bitfield(&self) -> bool {
+    /// Both bitfield enums and plain newtype enums will cause this to return
+    /// true.
+    fn is_newtype(&self) -> bool {
         match *self {
-            EnumVariation::Bitfield {..} => true,
+            EnumVariation::NewType { .. } => true,
             _ => false
         }
     }
@@ -2142,13 +2964,16 @@ enum EnumBuilder<'a> {
         codegen_depth: usize,
         attrs: Vec<quote::Tokens>,
         ident: Term,
+        repr: quote::Tokens,
         tokens: quote::Tokens,
+        try_from_arms: Vec<quote::Tokens>,
         emitted_any_variants: bool,
     },
-    Bitfield {
+    NewType {
         codegen_depth: usize,
         canonical_name: &'a str,
         tokens: quote::Tokens,
+        is_bitfield: bool,
     },
     Consts {
         variants: Vec<quote::Tokens>,
@@ -2166,7 +2991,7 @@ impl<'a> EnumBuilder<'a> {
     fn codegen_depth(&self) -> usize {
         match *self {
             EnumBuilder::Rust { codegen_depth, .. } |
-            EnumBuilder::Bitfield { codegen_depth, .. } |
+            EnumBuilder::NewType { codegen_depth, .. } |
             EnumBuilder::ModuleConsts { codegen_depth, .. } |
             EnumBuilder::Consts { codegen_depth, .. } => codegen_depth,
         }
@@ -2184,24 +3009,27 @@ impl<'a> EnumBuilder<'a> {
         let ident = Term::new(name, Span::call_site());
 
         match enum_variation {
-            EnumVariation::Bitfield => {
-                EnumBuilder::Bitfield {
+            EnumVariation::NewType { is_bitfield } => {
+                EnumBuilder::NewType {
                     codegen_depth: enum_codegen_depth,
                     canonical_name: name,
                     tokens: quote! {
                         #( #attrs )*
                         pub struct #ident (pub #repr);
                     },
+                    is_bitfield,
                 }
             }
 
-            EnumVariation::Rust => {
+            EnumVariation::Rust { .. } => {
                 let tokens = quote!();
                 EnumBuilder::Rust {
                     codegen_depth: enum_codegen_depth + 1,
                     attrs,
                     ident,
+                    repr,
                     tokens,
+                    try_from_arms: vec![],
                     emitted_any_variants: false,
                 }
             }
@@ -2259,22 +3087,35 @@ impl<'a> EnumBuilder<'a> {
         }
 
         match self {
-            EnumBuilder::Rust { attrs, ident, tokens, emitted_any_variants: _, codegen_depth } => {
+            EnumBuilder::Rust {
+                attrs,
+                ident,
+                repr,
+                tokens,
+                mut try_from_arms,
+                emitted_any_variants: _,
+                codegen_depth,
+            } => {
                 let name = ctx.rust_ident(variant_name);
+                try_from_arms.push(quote! {
+                    #expr => Ok(#ident :: #name),
+                });
                 EnumBuilder::Rust {
                     attrs,
                     ident,
+                    repr,
                     codegen_depth,
                     tokens: quote! {
                         #tokens
                         #doc
                         #name = #expr,
                     },
+                    try_from_arms,
                     emitted_any_variants: true,
                 }
             }
 
-            EnumBuilder::Bitfield { canonical_name, .. } => {
+            EnumBuilder::NewType { canonical_name, .. } => {
                 if ctx.options().rust_features().associated_const && is_ty_named {
                     let enum_ident = ctx.rust_ident(canonical_name);
                     let variant_ident = ctx.rust_ident(variant_name);
@@ -2287,7 +3128,11 @@ impl<'a> EnumBuilder<'a> {
                 } else {
                     let ident = ctx.rust_ident(match mangling_prefix {
                         Some(prefix) => {
-                            Cow::Owned(format!("{}_{}", prefix, variant_name))
+                            Cow::Owned(format!(
+                                "{}_{}",
+                                prefix,
+                                helpers::unraw(&variant_name)
+                            ))
                         }
                         None => variant_name,
                     });
@@ -2305,7 +3150,11 @@ impl<'a> EnumBuilder<'a> {
             } => {
                 let constant_name = match mangling_prefix {
                     Some(prefix) => {
-                        Cow::Owned(format!("{}_{}", prefix, variant_name))
+                        Cow::Owned(format!(
+                            "{}_{}",
+                            prefix,
+                            helpers::unraw(&variant_name)
+                        ))
                     }
                     None => variant_name,
                 };
@@ -2346,13 +3195,53 @@ impl<'a> EnumBuilder<'a> {
         result: &mut CodegenResult<'b>,
     ) -> quote::Tokens {
         match self {
-            EnumBuilder::Rust { attrs, ident, tokens, emitted_any_variants, .. } => {
+            EnumBuilder::Rust {
+                attrs,
+                ident,
+                repr,
+                tokens,
+                try_from_arms,
+                emitted_any_variants,
+                ..
+            } => {
                 let variants = if !emitted_any_variants {
                     quote!(__bindgen_cannot_repr_c_on_empty_enum = 0)
                 } else {
                     tokens
                 };
 
+                if ctx.options().impl_try_from {
+                    if ctx.options().rust_features().try_from {
+                        let prefix = ctx.trait_prefix();
+                        let unknown_value = if ctx.options().enable_cxx_namespaces {
+                            quote! { root::__BindgenUnknownEnumValue }
+                        } else {
+                            quote! { __BindgenUnknownEnumValue }
+                        };
+
+                        result.saw_enum_try_from();
+                        result.push(quote! {
+                            impl ::#prefix::convert::TryFrom<#repr> for #ident {
+                                type Error = #unknown_value<#repr>;
+
+                                #[inline]
+                                fn try_from(value: #repr) -> ::#prefix::result::Result<Self, Self::Error> {
+                                    match value {
+                                        #( #try_from_arms )*
+                                        _ => Err(#unknown_value(value)),
+                                    }
+                                }
+                            }
+                        });
+                    } else {
+                        warn!(
+                            "Skipping TryFrom impl for {}: the target Rust \
+                             version doesn't support TryFrom",
+                            ident
+                        );
+                    }
+                }
+
                 quote! {
                     #( #attrs )*
                     pub enum #ident {
@@ -2360,11 +3249,16 @@ impl<'a> EnumBuilder<'a> {
                     }
                 }
             }
-            EnumBuilder::Bitfield {
+            EnumBuilder::NewType {
                 canonical_name,
                 tokens,
+                is_bitfield,
                 ..
             } => {
+                if !is_bitfield {
+                    return tokens;
+                }
+
                 let rust_ty_name = ctx.rust_ident_raw(canonical_name);
                 let prefix = ctx.trait_prefix();
 
@@ -2487,9 +3381,13 @@ impl CodeGenerator for Enum {
         let variation = if self.is_constified_enum_module(ctx, item) {
             EnumVariation::ModuleConsts
         } else if self.is_bitfield(ctx, item) {
-            EnumVariation::Bitfield
+            EnumVariation::NewType { is_bitfield: true }
+        } else if self.is_newtype(ctx, item) {
+            EnumVariation::NewType { is_bitfield: false }
+        } else if self.is_rustified_non_exhaustive_enum(ctx, item) {
+            EnumVariation::Rust { non_exhaustive: true }
         } else if self.is_rustified_enum(ctx, item) {
-            EnumVariation::Rust
+            EnumVariation::Rust { non_exhaustive: false }
         } else {
             // We generate consts by default
             EnumVariation::Consts
@@ -2498,10 +3396,25 @@ impl CodeGenerator for Enum {
         let mut attrs = vec![];
 
         // TODO(emilio): Delegate this to the builders?
-        if variation.is_rust() {
+        if let EnumVariation::Rust { non_exhaustive } = variation {
             attrs.push(attributes::repr(repr_name));
-        } else if variation.is_bitfield() {
-            attrs.push(attributes::repr("C"));
+            if non_exhaustive {
+                if ctx.options().rust_features().non_exhaustive {
+                    attrs.push(attributes::non_exhaustive());
+                } else {
+                    warn!(
+                        "Not marking {} as #[non_exhaustive]: the target \
+                         Rust version doesn't support it",
+                        name
+                    );
+                }
+            }
+        } else if variation.is_newtype() {
+            if ctx.options().rust_features().repr_transparent {
+                attrs.push(attributes::repr("transparent"));
+            } else {
+                attrs.push(attributes::repr("C"));
+            }
         }
 
         if let Some(comment) = item.comment(ctx) {
@@ -2529,7 +3442,11 @@ impl CodeGenerator for Enum {
         ) {
             let constant_name = if enum_.name().is_some() {
                 if ctx.options().prepend_enum_name {
-                    format!("{}_{}", enum_canonical_name.as_str(), variant_name)
+                    format!(
+                        "{}_{}",
+                        enum_canonical_name.as_str(),
+                        helpers::unraw(variant_name)
+                    )
                 } else {
                     variant_name.into()
                 }
@@ -2608,9 +3525,11 @@ impl CodeGenerator for Enum {
                                 let parent_name =
                                     parent_canonical_name.as_ref().unwrap();
 
-                                Cow::Owned(
-                                    format!("{}_{}", parent_name, variant_name),
-                                )
+                                Cow::Owned(format!(
+                                    "{}_{}",
+                                    parent_name,
+                                    helpers::unraw(&variant_name)
+                                ))
                             };
 
                         let existing_variant_name = entry.get();
@@ -2964,17 +3883,66 @@ impl TryToRustTy for Type {
                             #ident
                         })
                     }
-                    // FIXME: This doesn't generate the proper alignment, but we
-                    // can't do better right now. We should be able to use
-                    // i128/u128 when they're available.
+                    IntKind::I128 if ctx.options().rust_features().i128_and_u128 => {
+                        Ok(quote! { i128 })
+                    }
+                    IntKind::U128 if ctx.options().rust_features().i128_and_u128 => {
+                        Ok(quote! { u128 })
+                    }
+                    // FIXME: This doesn't generate the proper alignment, but
+                    // older Rust versions align `i128` and `u128` differently
+                    // than C does, so we can't do better.
                     IntKind::U128 | IntKind::I128 => {
                         Ok(quote! { [u64; 2] })
                     }
                 }
             }
-            TypeKind::Float(fk) => Ok(float_kind_rust_type(ctx, fk)),
+            TypeKind::Float(fk) => {
+                Ok(float_kind_rust_type(ctx, fk, self.layout(ctx)))
+            }
+            TypeKind::Atomic(kind) => {
+                let prefix = ctx.trait_prefix();
+                let name = match kind {
+                    AtomicKind::Bool => "AtomicBool",
+                    AtomicKind::Ptr(inner) => {
+                        let pointee = inner.to_rust_ty_or_opaque(ctx, &());
+                        return Ok(quote! {
+                            ::#prefix::sync::atomic::AtomicPtr<#pointee>
+                        });
+                    }
+                    AtomicKind::Int(IntKind::Custom { name: "usize", .. }) => {
+                        "AtomicUsize"
+                    }
+                    AtomicKind::Int(IntKind::Custom { name: "isize", .. }) => {
+                        "AtomicIsize"
+                    }
+                    AtomicKind::Int(ik) => {
+                        if !ctx.options().rust_features().integer_atomics {
+                            return self.try_to_opaque(ctx, item);
+                        }
+                        let size = self.layout(ctx).map_or(0, |l| l.size);
+                        match (size, ik.is_signed()) {
+                            (1, true) => "AtomicI8",
+                            (1, false) => "AtomicU8",
+                            (2, true) => "AtomicI16",
+                            (2, false) => "AtomicU16",
+                            (4, true) => "AtomicI32",
+                            (4, false) => "AtomicU32",
+                            (8, true) => "AtomicI64",
+                            (8, false) => "AtomicU64",
+                            _ => return self.try_to_opaque(ctx, item),
+                        }
+                    }
+                };
+                let name = ctx.rust_ident_raw(name);
+                Ok(quote! {
+                    ::#prefix::sync::atomic::#name
+                })
+            }
             TypeKind::Complex(fk) => {
-                let float_path = float_kind_rust_type(ctx, fk);
+                let float_layout = self.layout(ctx)
+                    .map(|l| Layout::new(l.size / 2, l.align));
+                let float_path = float_kind_rust_type(ctx, fk, float_layout);
 
                 ctx.generated_bindegen_complex();
                 Ok(if ctx.options().enable_cxx_namespaces {
@@ -2999,7 +3967,10 @@ impl TryToRustTy for Type {
                     ::#prefix::option::Option<#ty>
                 })
             }
-            TypeKind::Array(item, len) => {
+            // Vectors that aren't behind a typedef have no type of their own,
+            // so they lose their alignment.
+            TypeKind::Array(item, len) |
+            TypeKind::Vector(item, len) => {
                 let ty = item.try_to_rust_ty(ctx, &())?;
                 Ok(quote! {
                     [ #ty ; #len ]
@@ -3213,11 +4184,14 @@ impl CodeGenerator for Function {
         debug!("<Function as CodeGenerator>::codegen: item = {:?}", item);
         debug_assert!(item.is_enabled_for_codegen(ctx));
 
-        // We can't currently do anything with Internal functions so just
-        // avoid generating anything for them.
+        // Internal functions have no symbol to link against, so we can only
+        // bind them through an exported wrapper.
+        let wrapper_name = self.c_prototype().map(|_| {
+            format!("{}{}", self.name(), ctx.options().wrap_static_fns_suffix())
+        });
         match self.linkage() {
-            Linkage::Internal => return,
-            Linkage::External => {}
+            Linkage::Internal if wrapper_name.is_none() => return,
+            Linkage::Internal | Linkage::External => {}
         }
 
         // Pure virtual methods have no actual symbol, so we can't generate
@@ -3237,6 +4211,29 @@ impl CodeGenerator for Function {
             return;
         }
 
+        let signature_item = ctx.resolve_item(self.signature());
+        let signature = signature_item.kind().expect_type().canonical_type(ctx);
+        let signature = match *signature.kind() {
+            TypeKind::Function(ref sig) => sig,
+            _ => panic!("Signature kind is not a Function: {:?}", signature),
+        };
+
+        // Rust wouldn't pass some values the same way C does, so calling such
+        // functions would silently misbehave.
+        let mut by_value = signature
+            .argument_types()
+            .iter()
+            .map(|&(_, ty)| ty)
+            .chain(iter::once(signature.return_type()));
+        if by_value.any(|ty| utils::is_abi_incompatible_by_value(ctx, ty)) {
+            warn!(
+                "Skipping function {}, whose arguments or return value Rust \
+                 can't pass like C does",
+                self.name()
+            );
+            return;
+        }
+
         let name = self.name();
         let mut canonical_name = item.canonical_name(ctx);
         let mangled_name = self.mangled_name();
@@ -3252,12 +4249,11 @@ impl CodeGenerator for Function {
             result.saw_function(seen_symbol_name);
         }
 
-        let signature_item = ctx.resolve_item(self.signature());
-        let signature = signature_item.kind().expect_type().canonical_type(ctx);
-        let signature = match *signature.kind() {
-            TypeKind::Function(ref sig) => sig,
-            _ => panic!("Signature kind is not a Function: {:?}", signature),
-        };
+        if let (Some(prototype), Some(wrapper_name)) =
+            (self.c_prototype(), wrapper_name.as_ref())
+        {
+            result.static_wrappers.push(prototype.wrapper(name, wrapper_name));
+        }
 
         let args = utils::fnsig_arguments(ctx, signature);
         let ret = utils::fnsig_return_ty(ctx, signature);
@@ -3268,7 +4264,19 @@ impl CodeGenerator for Function {
             attributes.push(attributes::doc(comment));
         }
 
-        if let Some(mangled) = mangled_name {
+        if signature.must_use() &&
+            ctx.options().rust_features().must_use_function
+        {
+            attributes.push(attributes::must_use());
+        }
+
+        if let Some(ref wrapper_name) = wrapper_name {
+            // The wrapper is a plain C function, so let the platform mangle
+            // its name as usual.
+            attributes.push(quote! {
+                #[link_name = #wrapper_name]
+            });
+        } else if let Some(mangled) = mangled_name {
             attributes.push(attributes::link_name(mangled));
         } else if name != canonical_name {
             attributes.push(attributes::link_name(name));
@@ -3278,6 +4286,7 @@ impl CodeGenerator for Function {
         // suffix.
         let times_seen = result.overload_number(&canonical_name);
         if times_seen > 0 {
+            canonical_name = helpers::unraw(&canonical_name).to_owned();
             write!(&mut canonical_name, "{}", times_seen).unwrap();
         }
 
@@ -3298,7 +4307,28 @@ impl CodeGenerator for Function {
         };
 
         let ident = ctx.rust_ident(canonical_name);
-        let tokens = quote!( extern #abi {
+
+        // When loading the library at runtime, the function is looked up by its
+        // symbol name rather than declared. `dlsym` adds the leading underscore
+        // of Apple platforms itself, so the mangled name goes without it.
+        if ctx.options().dynamic_library_name.is_some() {
+            let mangled_name = mangled_name.map(|mangled| {
+                if ctx.target_is_apple() && mangled.starts_with('_') {
+                    &mangled[1..]
+                } else {
+                    mangled
+                }
+            });
+            let symbol = wrapper_name.as_ref()
+                .map(|n| &**n)
+                .or(mangled_name)
+                .unwrap_or(name);
+            result.dynamic_items.push(ctx, ident, symbol, abi, signature);
+            return;
+        }
+
+        let maybe_unsafe = utils::extern_block_unsafety(ctx);
+        let tokens = quote!( #maybe_unsafe extern #abi {
             #(#attributes)*
             pub fn #ident ( #( #args ),* ) #ret;
         });
@@ -3426,8 +4456,10 @@ impl CodeGenerator for ObjCInterface {
     }
 }
 
-pub(crate) fn codegen(context: BindgenContext) -> (Vec<quote::Tokens>, BindgenOptions) {
-    context.gen(|context| {
+pub(crate) fn codegen(
+    context: BindgenContext,
+) -> (Vec<quote::Tokens>, Vec<String>, Vec<String>, BindgenOptions) {
+    let ((items, static_wrappers, c_asserts), options) = context.gen(|context| {
         let _t = context.timer("codegen");
         let counter = Cell::new(0);
         let mut result = CodegenResult::new(&counter);
@@ -3452,16 +4484,21 @@ pub(crate) fn codegen(context: BindgenContext) -> (Vec<quote::Tokens>, BindgenOp
         context.resolve_item(context.root_module())
             .codegen(context, &mut result, &());
 
-        result.items
-    })
+        (result.items, result.static_wrappers, result.c_layout_assertions)
+    });
+
+    (items, static_wrappers, c_asserts, options)
 }
 
 mod utils {
     use super::{ToRustTyOrOpaque, error};
-    use ir::context::BindgenContext;
+    use super::helpers::attributes;
+    use ir::context::{BindgenContext, TypeId};
     use ir::function::FunctionSig;
+    use ir::int::IntKind;
     use ir::item::{Item, ItemCanonicalPath};
-    use ir::ty::TypeKind;
+    use ir::layout::Layout;
+    use ir::ty::{FloatKind, TypeKind};
     use quote;
     use proc_macro2::{Term, Span};
     use std::mem;
@@ -3673,6 +4710,19 @@ mod utils {
         result.extend(old_items.into_iter());
     }
 
+    pub fn prepend_unknown_enum_value_type(
+        result: &mut Vec<quote::Tokens>,
+    ) {
+        let unknown_enum_value_type = quote! {
+            #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+            pub struct __BindgenUnknownEnumValue<T>(pub T);
+        };
+
+        let items = vec![unknown_enum_value_type];
+        let old_items = mem::replace(result, items);
+        result.extend(old_items.into_iter());
+    }
+
     pub fn prepend_complex_type(
         result: &mut Vec<quote::Tokens>,
     ) {
@@ -3690,6 +4740,26 @@ mod utils {
         result.extend(old_items.into_iter());
     }
 
+    pub fn prepend_float16_type(
+        ctx: &BindgenContext,
+        result: &mut Vec<quote::Tokens>,
+    ) {
+        let repr = if ctx.options().rust_features().repr_transparent {
+            attributes::repr("transparent")
+        } else {
+            attributes::repr("C")
+        };
+        let float16_type = quote! {
+            #[derive(PartialEq, Copy, Clone, Hash, Debug, Default)]
+            #repr
+            pub struct __BindgenFloat16(pub u16);
+        };
+
+        let items = vec![float16_type];
+        let old_items = mem::replace(result, items);
+        result.extend(old_items.into_iter());
+    }
+
     pub fn build_path(
         item: &Item,
         ctx: &BindgenContext,
@@ -3734,6 +4804,103 @@ mod utils {
         })
     }
 
+    /// The `core::arch` type for a well-known vector typedef, like `__m128` or
+    /// `float32x4_t`, if the target's architecture has it.
+    pub fn arch_vector_type(
+        ctx: &BindgenContext,
+        name: &str,
+    ) -> Option<quote::Tokens> {
+        let arch = ctx.arch_vector_module(name)?;
+        let prefix = ctx.trait_prefix();
+        let arch = ctx.rust_ident_raw(arch);
+        let name = ctx.rust_ident_raw(name);
+        Some(quote! {
+            ::#prefix::arch::#arch::#name
+        })
+    }
+
+    /// Whether values of the given type can't be passed to or returned from
+    /// C functions, like vectors, which stable Rust doesn't allow in FFI even
+    /// as `core::arch` types, and which wouldn't be passed in registers like
+    /// in C as plain arrays, or the scalars we represent with a stand-in of
+    /// the same layout, like `long double`.
+    pub fn is_abi_incompatible_by_value(
+        ctx: &BindgenContext,
+        ty: TypeId,
+    ) -> bool {
+        let features = ctx.options().rust_features();
+        let ty = ctx.resolve_type(ty);
+        match *ty.kind() {
+            TypeKind::Vector(..) => true,
+            TypeKind::Int(IntKind::I128) |
+            TypeKind::Int(IntKind::U128) => !features.i128_and_u128,
+            TypeKind::Float(fk) => {
+                is_abi_incompatible_float(ctx, fk, ty.layout(ctx))
+            }
+            TypeKind::Complex(fk) => {
+                let float_layout = ty.layout(ctx)
+                    .map(|l| Layout::new(l.size / 2, l.align));
+                is_abi_incompatible_float(ctx, fk, float_layout)
+            }
+            TypeKind::Alias(inner) |
+            TypeKind::TemplateAlias(inner, _) |
+            TypeKind::ResolvedTypeRef(inner) => {
+                is_abi_incompatible_by_value(ctx, inner)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_abi_incompatible_float(
+        ctx: &BindgenContext,
+        fk: FloatKind,
+        layout: Option<Layout>,
+    ) -> bool {
+        match fk {
+            FloatKind::Float | FloatKind::Double => false,
+            FloatKind::Float16 => !ctx.options().rust_features().f16,
+            // Unless it's just a `double`, see `float_kind_rust_type`.
+            FloatKind::LongDouble => layout.map_or(false, |l| l.size != 8),
+            FloatKind::Float128 => true,
+        }
+    }
+
+    /// The `unsafe` qualifier for `extern` blocks, if the target requires
+    /// it.
+    pub fn extern_block_unsafety(ctx: &BindgenContext) -> quote::Tokens {
+        if ctx.options().rust_features().unsafe_extern_blocks {
+            quote! { unsafe }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// The declaration of the `__bindgen_tmp` variable a constructor
+    /// initializes, the pointer to pass as the constructor's `this`, and the
+    /// expression yielding the initialized value.
+    pub fn constructor_tmp_variable(
+        ctx: &BindgenContext,
+    ) -> (quote::Tokens, quote::Tokens, quote::Tokens) {
+        let prefix = ctx.trait_prefix();
+        if ctx.options().rust_features().maybe_uninit {
+            (
+                quote! {
+                    let mut __bindgen_tmp = ::#prefix::mem::MaybeUninit::uninit()
+                },
+                quote! { __bindgen_tmp.as_mut_ptr() },
+                quote! { __bindgen_tmp.assume_init() },
+            )
+        } else {
+            (
+                quote! {
+                    let mut __bindgen_tmp = ::#prefix::mem::uninitialized()
+                },
+                quote! { &mut __bindgen_tmp },
+                quote! { __bindgen_tmp },
+            )
+        }
+    }
+
     pub fn fnsig_return_ty(
         ctx: &BindgenContext,
         sig: &FunctionSig,
@@ -3811,4 +4978,29 @@ mod utils {
 
         args
     }
+
+    /// The identifiers of the arguments of `sig`, as named by
+    /// `fnsig_arguments`.
+    pub fn fnsig_argument_identifiers(
+        ctx: &BindgenContext,
+        sig: &FunctionSig,
+    ) -> Vec<quote::Tokens> {
+        let mut unnamed_arguments = 0;
+        sig.argument_types().iter().map(|&(ref name, _ty)| {
+            let arg_name = match *name {
+                Some(ref name) => ctx.rust_mangle(name).into_owned(),
+                None => {
+                    unnamed_arguments += 1;
+                    format!("arg{}", unnamed_arguments)
+                }
+            };
+
+            assert!(!arg_name.is_empty());
+            let arg_name = ctx.rust_ident(arg_name);
+
+            quote! {
+                #arg_name
+            }
+        }).collect()
+    }
 }
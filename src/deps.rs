@@ -0,0 +1,55 @@
+//! Generating Makefile-style dependency files.
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+/// Where to write a depfile, and which target it describes.
+#[derive(Clone, Debug)]
+pub struct DepfileSpec {
+    /// The file the bindings are written to, i.e. the target of the rule.
+    pub output_module: String,
+    /// The path of the depfile itself.
+    pub depfile_path: PathBuf,
+}
+
+impl DepfileSpec {
+    /// Write a rule making the bindings depend on every file in `deps`.
+    pub fn write(&self, deps: &BTreeSet<String>) -> io::Result<()> {
+        let mut buf = escape(&self.output_module);
+        buf.push(':');
+        for dep in deps {
+            buf.push(' ');
+            buf.push_str(&escape(dep));
+        }
+        buf.push('\n');
+
+        let mut file = File::create(&self.depfile_path)?;
+        file.write_all(buf.as_bytes())
+    }
+}
+
+/// Escape the characters Make would otherwise treat specially in a path.
+fn escape(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            ' ' | '#' => escaped.push('\\'),
+            '$' => escaped.push('$'),
+            _ => {}
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_special_characters() {
+        assert_eq!(escape("a b#c$d"), "a\\ b\\#c$$d");
+    }
+}
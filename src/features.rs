@@ -96,6 +96,32 @@ macro_rules! rust_target_base {
             => Stable_1_21 => 1.21;
             /// Rust stable 1.25
             => Stable_1_25 => 1.25;
+            /// Rust stable 1.27
+            => Stable_1_27 => 1.27;
+            /// Rust stable 1.28
+            => Stable_1_28 => 1.28;
+            /// Rust stable 1.30
+            => Stable_1_30 => 1.30;
+            /// Rust stable 1.31
+            => Stable_1_31 => 1.31;
+            /// Rust stable 1.34
+            => Stable_1_34 => 1.34;
+            /// Rust stable 1.36
+            => Stable_1_36 => 1.36;
+            /// Rust stable 1.40
+            => Stable_1_40 => 1.40;
+            /// Rust stable 1.49
+            => Stable_1_49 => 1.49;
+            /// Rust stable 1.51
+            => Stable_1_51 => 1.51;
+            /// Rust stable 1.57
+            => Stable_1_57 => 1.57;
+            /// Rust stable 1.64
+            => Stable_1_64 => 1.64;
+            /// Rust stable 1.77
+            => Stable_1_77 => 1.77;
+            /// Rust stable 1.82
+            => Stable_1_82 => 1.82;
             /// Nightly rust
             => Nightly => nightly;
         );
@@ -106,7 +132,54 @@ rust_target_base!(rust_target_def);
 rust_target_base!(rust_target_values_def);
 
 /// Latest stable release of Rust
-pub const LATEST_STABLE_RUST: RustTarget = RustTarget::Stable_1_21;
+pub const LATEST_STABLE_RUST: RustTarget = RustTarget::Stable_1_82;
+
+impl RustTarget {
+    /// Create a `RustTarget` from a crate's minimum supported Rust version,
+    /// as given by the `rust-version` key of its `Cargo.toml`.
+    ///
+    /// The version is of the form "1.70" or "1.70.0", and maps to the newest
+    /// target that release supports.
+    pub fn from_rust_version(version: &str) -> Result<Self, io::Error> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "Got an invalid rust version \"{}\". Accepted values are \
+                     of the form \"1.70\" or \"1.70.0\".",
+                    version
+                ),
+            )
+        };
+
+        let mut parts = version.trim().split('.');
+        let major = parts.next().and_then(|p| p.parse::<u64>().ok());
+        let minor = parts.next().and_then(|p| p.parse::<u64>().ok());
+        let minor = match (major, minor) {
+            (Some(1), Some(minor)) => minor,
+            _ => return Err(invalid()),
+        };
+        if parts.next().map_or(false, |p| p.parse::<u64>().is_err()) {
+            return Err(invalid());
+        }
+
+        let target = RUST_TARGET_STRINGS
+            .iter()
+            .filter_map(|target| {
+                let target_minor = target.split('.').nth(1)?.parse::<u64>().ok()?;
+                if target_minor <= minor {
+                    Some((target_minor, target))
+                } else {
+                    None
+                }
+            })
+            .max()
+            .map(|(_, target)| target)
+            .ok_or_else(invalid)?;
+
+        RustTarget::from_str(target)
+    }
+}
 
 /// Create RustFeatures struct definition, new(), and a getter for each field
 macro_rules! rust_feature_def {
@@ -146,6 +219,46 @@ rust_feature_def!(
     => repr_align;
     /// associated constants https://github.com/rust-lang/rust/issues/29646
     => associated_const;
+    /// `#[must_use]` on functions https://github.com/rust-lang/rust/pull/48925
+    => must_use_function;
+    /// repr(transparent) https://github.com/rust-lang/rust/pull/51562
+    => repr_transparent;
+    /// Raw identifiers https://github.com/rust-lang/rust/pull/53236
+    => raw_identifiers;
+    /// `const fn` https://github.com/rust-lang/rust/pull/54835
+    => min_const_fn;
+    /// `TryFrom` and `TryInto` https://github.com/rust-lang/rust/issues/33417
+    => try_from;
+    /// `const` `wrapping_*` integer methods https://github.com/rust-lang/rust/blob/master/RELEASES.md#version-1320-2019-01-17
+    => const_wrapping_ops;
+    /// `AtomicI8` and friends https://github.com/rust-lang/rust/pull/57425
+    => integer_atomics;
+    /// `#[non_exhaustive]` https://github.com/rust-lang/rust/issues/44109
+    => non_exhaustive;
+    /// `ManuallyDrop<T>` fields in unions https://github.com/rust-lang/rust/pull/77547
+    => manually_drop_union;
+    /// `MaybeUninit` https://github.com/rust-lang/rust/pull/60445
+    => maybe_uninit;
+    /// `ptr::addr_of!` https://github.com/rust-lang/rust/pull/80886
+    => raw_ref_macros;
+    /// `panic!` and `assert!` in constants https://github.com/rust-lang/rust/pull/89508
+    => const_panic;
+    /// `core::ffi::c_*` types https://github.com/rust-lang/rust/pull/98315
+    => core_ffi_c;
+    /// `mem::offset_of!` https://github.com/rust-lang/rust/pull/118799
+    => offset_of;
+    /// `unsafe extern` blocks https://github.com/rust-lang/rust/pull/127921
+    => unsafe_extern_blocks;
+    /// `i128` and `u128` aligned like C's `__int128` https://github.com/rust-lang/rust/pull/116672
+    => i128_and_u128;
+    /// `f16` https://github.com/rust-lang/rust/issues/116909
+    => f16;
+    /// `core::arch` x86 vector types https://github.com/rust-lang/rust/pull/49664
+    => arch_x86_vectors;
+    /// `core::arch` AArch64 vector types https://github.com/rust-lang/rust/pull/90972
+    => arch_aarch64_vectors;
+    /// `#[repr(simd)]` https://github.com/rust-lang/rust/issues/27731
+    => repr_simd;
 );
 
 impl From<RustTarget> for RustFeatures {
@@ -168,8 +281,67 @@ impl From<RustTarget> for RustFeatures {
             features.repr_align = true;
         }
 
+        if rust_target >= RustTarget::Stable_1_27 {
+            features.must_use_function = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_28 {
+            features.repr_transparent = true;
+            features.arch_x86_vectors = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_30 {
+            features.raw_identifiers = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_31 {
+            features.min_const_fn = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_34 {
+            features.try_from = true;
+            features.const_wrapping_ops = true;
+            features.integer_atomics = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_36 {
+            features.maybe_uninit = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_40 {
+            features.non_exhaustive = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_49 {
+            features.manually_drop_union = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_51 {
+            features.raw_ref_macros = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_57 {
+            features.const_panic = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_64 {
+            features.core_ffi_c = true;
+            features.arch_aarch64_vectors = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_77 {
+            features.offset_of = true;
+            features.i128_and_u128 = true;
+        }
+
+        if rust_target >= RustTarget::Stable_1_82 {
+            features.unsafe_extern_blocks = true;
+        }
+
         if rust_target >= RustTarget::Nightly {
             features.thiscall_abi = true;
+            features.f16 = true;
+            features.repr_simd = true;
         }
 
         features
@@ -200,6 +372,32 @@ mod test {
         test_target("1.19", RustTarget::Stable_1_19);
         test_target("1.21", RustTarget::Stable_1_21);
         test_target("1.25", RustTarget::Stable_1_25);
+        test_target("1.27", RustTarget::Stable_1_27);
+        test_target("1.28", RustTarget::Stable_1_28);
+        test_target("1.30", RustTarget::Stable_1_30);
+        test_target("1.31", RustTarget::Stable_1_31);
+        test_target("1.34", RustTarget::Stable_1_34);
+        test_target("1.36", RustTarget::Stable_1_36);
+        test_target("1.40", RustTarget::Stable_1_40);
+        test_target("1.49", RustTarget::Stable_1_49);
+        test_target("1.51", RustTarget::Stable_1_51);
+        test_target("1.57", RustTarget::Stable_1_57);
+        test_target("1.64", RustTarget::Stable_1_64);
+        test_target("1.77", RustTarget::Stable_1_77);
+        test_target("1.82", RustTarget::Stable_1_82);
         test_target("nightly", RustTarget::Nightly);
     }
+
+    #[test]
+    fn rust_version_to_target() {
+        let target = |v| RustTarget::from_rust_version(v).unwrap();
+        assert_eq!(target("1.0"), RustTarget::Stable_1_0);
+        assert_eq!(target("1.40"), RustTarget::Stable_1_40);
+        assert_eq!(target("1.40.1"), RustTarget::Stable_1_40);
+        assert_eq!(target("1.63.0"), RustTarget::Stable_1_57);
+        assert_eq!(target("1.95"), RustTarget::Stable_1_82);
+        assert!(RustTarget::from_rust_version("2.0").is_err());
+        assert!(RustTarget::from_rust_version("1").is_err());
+        assert!(RustTarget::from_rust_version("nightly").is_err());
+    }
 }
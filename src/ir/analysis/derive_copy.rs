@@ -21,9 +21,10 @@ use std::collections::HashSet;
 ///
 /// * If T is Opaque and layout of the type is known, get this layout as opaque
 ///   type and check whether it can be derived using trivial checks.
-/// * If T is Array type, copy cannot be derived if the length of the array is
+/// * If T is Array or Vector type, copy cannot be derived if the length of the array is
 ///   larger than the limit or the type of data the array contains cannot derive
 ///   copy.
+/// * If T is an atomic type, copy cannot be derived.
 /// * If T is a type alias, a templated alias or an indirection to another type,
 ///   copy cannot be derived if the type T refers to cannot be derived copy.
 /// * If T is a compound type, copy cannot be derived if any of its base member
@@ -182,7 +183,13 @@ impl<'ctx> MonotoneFramework for CannotDeriveCopy<'ctx> {
                 ConstrainResult::Same
             }
 
-            TypeKind::Array(t, len) => {
+            TypeKind::Atomic(..) => {
+                trace!("    atomics cannot derive Copy");
+                self.insert(id)
+            }
+
+            TypeKind::Array(t, len) |
+            TypeKind::Vector(t, len) => {
                 let cant_derive_copy = self.is_not_copy(t);
                 if cant_derive_copy {
                     trace!(
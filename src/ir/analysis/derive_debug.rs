@@ -20,7 +20,7 @@ use std::collections::HashSet;
 ///
 /// * If T is Opaque and layout of the type is known, get this layout as opaque
 ///   type and check whether it can be derived using trivial checks.
-/// * If T is Array type, debug cannot be derived if the length of the array is
+/// * If T is Array or Vector type, debug cannot be derived if the length of the array is
 ///   larger than the limit or the type of data the array contains cannot derive
 ///   debug.
 /// * If T is a type alias, a templated alias or an indirection to another type,
@@ -183,6 +183,7 @@ impl<'ctx> MonotoneFramework for CannotDeriveDebug<'ctx> {
             TypeKind::Enum(..) |
             TypeKind::Reference(..) |
             TypeKind::BlockPointer |
+            TypeKind::Atomic(..) |
             TypeKind::TypeParam |
             TypeKind::UnresolvedTypeRef(..) |
             TypeKind::ObjCInterface(..) |
@@ -192,7 +193,8 @@ impl<'ctx> MonotoneFramework for CannotDeriveDebug<'ctx> {
                 ConstrainResult::Same
             }
 
-            TypeKind::Array(t, len) => {
+            TypeKind::Array(t, len) |
+            TypeKind::Vector(t, len) => {
                 if self.is_not_debug(t) {
                     trace!(
                         "    arrays of T for which we cannot derive Debug \
@@ -22,9 +22,11 @@ use std::collections::HashSet;
 ///
 /// * If T is Opaque and layout of the type is known, get this layout as opaque
 ///   type and check whether it can be derived using trivial checks.
-/// * If T is Array type, default cannot be derived if the length of the array is
+/// * If T is Array or Vector type, default cannot be derived if the length of the array is
 ///   larger than the limit or the type of data the array contains cannot derive
 ///   default.
+/// * If T is a vector typedef mapped to a `core::arch` type, default cannot be
+///   derived.
 /// * If T is a type alias, a templated alias or an indirection to another type,
 ///   default cannot be derived if the type T refers to cannot be derived default.
 /// * If T is a compound type, default cannot be derived if any of its base member
@@ -204,7 +206,8 @@ impl<'ctx> MonotoneFramework for CannotDeriveDefault<'ctx> {
             TypeKind::Function(..) |
             TypeKind::Int(..) |
             TypeKind::Float(..) |
-            TypeKind::Complex(..) => {
+            TypeKind::Complex(..) |
+            TypeKind::Atomic(..) => {
                 trace!("    simple type that can always derive Default");
                 ConstrainResult::Same
             }
@@ -223,7 +226,8 @@ impl<'ctx> MonotoneFramework for CannotDeriveDefault<'ctx> {
                 self.insert(id)
             }
 
-            TypeKind::Array(t, len) => {
+            TypeKind::Array(t, len) |
+            TypeKind::Vector(t, len) => {
                 if self.is_not_default(t) {
                     trace!(
                         "    arrays of T for which we cannot derive Default \
@@ -241,6 +245,11 @@ impl<'ctx> MonotoneFramework for CannotDeriveDefault<'ctx> {
                 }
             }
 
+            TypeKind::Alias(..) if ty.is_arch_vector(self.ctx) => {
+                trace!("    `core::arch` vectors don't implement Default");
+                self.insert(id)
+            }
+
             TypeKind::ResolvedTypeRef(t) |
             TypeKind::TemplateAlias(t, _) |
             TypeKind::Alias(t) => {
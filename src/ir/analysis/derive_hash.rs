@@ -20,9 +20,12 @@ use std::collections::HashSet;
 ///
 /// * If T is Opaque and layout of the type is known, get this layout as opaque
 ///   type and check whether it can be derived using trivial checks.
-/// * If T is Array type, hash cannot be derived if the length of the array is
+/// * If T is Array or Vector type, hash cannot be derived if the length of the array is
 ///   larger than the limit or the type of data the array contains cannot derive
 ///   hash.
+/// * If T is an atomic type, hash cannot be derived.
+/// * If T is a vector typedef mapped to a `core::arch` type, hash cannot be
+///   derived.
 /// * If T is a type alias, a templated alias or an indirection to another type,
 ///   hash cannot be derived if the type T refers to cannot be derived hash.
 /// * If T is a compound type, hash cannot be derived if any of its base member
@@ -182,7 +185,13 @@ impl<'ctx> MonotoneFramework for CannotDeriveHash<'ctx> {
                 self.insert(id)
             }
 
-            TypeKind::Array(t, len) => {
+            TypeKind::Atomic(..) => {
+                trace!("    atomics cannot derive Hash");
+                self.insert(id)
+            }
+
+            TypeKind::Array(t, len) |
+            TypeKind::Vector(t, len) => {
                 if self.cannot_derive_hash.contains(&t.into()) {
                     trace!(
                         "    arrays of T for which we cannot derive Hash \
@@ -227,6 +236,11 @@ impl<'ctx> MonotoneFramework for CannotDeriveHash<'ctx> {
                 ConstrainResult::Same
             }
 
+            TypeKind::Alias(..) if ty.is_arch_vector(self.ctx) => {
+                trace!("    `core::arch` vectors don't implement Hash");
+                self.insert(id)
+            }
+
             TypeKind::ResolvedTypeRef(t) |
             TypeKind::TemplateAlias(t, _) |
             TypeKind::Alias(t) => {
@@ -21,10 +21,15 @@ use std::collections::hash_map::Entry;
 /// * If T is Opaque and layout of the type is known, get this layout as opaque
 ///   type and check whether it can be derived using trivial checks.
 ///
-/// * If T is Array type, `PartialEq` or partialord cannot be derived if the array is incomplete, if the length of
+/// * If T is Array or Vector type, `PartialEq` or partialord cannot be derived if the array is incomplete, if the length of
 ///   the array is larger than the limit, or the type of data the array contains cannot derive
 ///   `PartialEq`/`PartialOrd`.
 ///
+/// * If T is an atomic type, `PartialEq`/`PartialOrd` cannot be derived.
+///
+/// * If T is a vector typedef mapped to a `core::arch` type,
+///   `PartialEq`/`PartialOrd` cannot be derived.
+///
 /// * If T is a type alias, a templated alias or an indirection to another type,
 ///   `PartialEq`/`PartialOrd` cannot be derived if the type T refers to cannot be
 ///   derived `PartialEq`/`PartialOrd`.
@@ -169,6 +174,11 @@ impl<'ctx> CannotDerivePartialEqOrPartialOrd<'ctx> {
                 return CanDerive::Yes;
             }
 
+            TypeKind::Atomic(..) => {
+                trace!("    atomics cannot derive `PartialEq`/`PartialOrd`");
+                return CanDerive::No;
+            }
+
             TypeKind::Array(t, len) => {
                 let inner_type = self.can_derive_partialeq_or_partialord
                     .get(&t.into())
@@ -200,6 +210,34 @@ impl<'ctx> CannotDerivePartialEqOrPartialOrd<'ctx> {
                 }
             }
 
+            TypeKind::Vector(t, len) => {
+                let inner_type = self.can_derive_partialeq_or_partialord
+                    .get(&t.into())
+                    .cloned()
+                    .unwrap_or(CanDerive::Yes);
+                if inner_type != CanDerive::Yes {
+                    trace!(
+                        "    vectors of T for which we cannot derive `PartialEq`/`PartialOrd` \
+                         also cannot derive `PartialEq`/`PartialOrd`"
+                    );
+                    return CanDerive::No;
+                }
+
+                // Unlike arrays, vectors aren't necessarily arrays in Rust, so
+                // we can't implement `PartialEq` for too large ones either.
+                if len <= RUST_DERIVE_IN_ARRAY_LIMIT {
+                    trace!(
+                        "    vector is small enough to derive `PartialEq`/`PartialOrd`"
+                    );
+                    return CanDerive::Yes;
+                } else {
+                    trace!(
+                        "    vector is too large to derive `PartialEq`/`PartialOrd`"
+                    );
+                    return CanDerive::No;
+                }
+            }
+
             TypeKind::Pointer(inner) => {
                 let inner_type =
                     self.ctx.resolve_type(inner).canonical_type(self.ctx);
@@ -271,6 +309,13 @@ impl<'ctx> CannotDerivePartialEqOrPartialOrd<'ctx> {
                 return self.constrain_join(item);
             }
 
+            TypeKind::Alias(..) if ty.is_arch_vector(self.ctx) => {
+                trace!(
+                    "    `core::arch` vectors don't implement `PartialEq`/`PartialOrd`"
+                );
+                return CanDerive::No;
+            }
+
             TypeKind::ResolvedTypeRef(..) |
             TypeKind::TemplateAlias(..) |
             TypeKind::Alias(..) |
@@ -123,6 +123,7 @@ impl<'ctx> MonotoneFramework for HasFloat<'ctx> {
             TypeKind::Enum(..) |
             TypeKind::Reference(..) |
             TypeKind::BlockPointer |
+            TypeKind::Atomic(..) |
             TypeKind::TypeParam |
             TypeKind::Opaque |
             TypeKind::Pointer(..) |
@@ -140,7 +141,8 @@ impl<'ctx> MonotoneFramework for HasFloat<'ctx> {
                 self.insert(id)
             }
 
-            TypeKind::Array(t, _) => {
+            TypeKind::Array(t, _) |
+            TypeKind::Vector(t, _) => {
                 if self.has_float.contains(&t.into()) {
                     trace!("    Array with type T that has float also has float");
                     return self.insert(id)
@@ -138,6 +138,8 @@ impl<'ctx> MonotoneFramework for HasTypeParameterInArray<'ctx> {
             TypeKind::TypeParam |
             TypeKind::Opaque |
             TypeKind::Pointer(..) |
+            TypeKind::Vector(..) |
+            TypeKind::Atomic(..) |
             TypeKind::UnresolvedTypeRef(..) |
             TypeKind::ObjCInterface(..) |
             TypeKind::ObjCId |
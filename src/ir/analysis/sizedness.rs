@@ -257,6 +257,7 @@ impl<'ctx> MonotoneFramework for SizednessAnalysis<'ctx> {
             TypeKind::Int(..) |
             TypeKind::Float(..) |
             TypeKind::Complex(..) |
+            TypeKind::Atomic(..) |
             TypeKind::Function(..) |
             TypeKind::Enum(..) |
             TypeKind::Reference(..) |
@@ -295,6 +296,10 @@ impl<'ctx> MonotoneFramework for SizednessAnalysis<'ctx> {
                 trace!("    arrays of > 0 elements are not zero-sized");
                 self.insert(id, SizednessResult::NonZeroSized)
             }
+            TypeKind::Vector(..) => {
+                trace!("    vectors are not zero-sized");
+                self.insert(id, SizednessResult::NonZeroSized)
+            }
 
             TypeKind::Comp(ref info) => {
                 trace!("    comp considers its own fields and bases");
@@ -49,7 +49,7 @@ fn preprocess_single_lines(comment: &str, indent: usize) -> String {
     let mut is_first = true;
     let lines: Vec<_> = comment
         .lines()
-        .map(|l| l.trim_left_matches('/').trim())
+        .map(|l| l.trim_start_matches('/').trim())
         .map(|l| {
             let indent = if is_first { "" } else { &*indent };
             is_first = false;
@@ -62,18 +62,18 @@ fn preprocess_single_lines(comment: &str, indent: usize) -> String {
 
 fn preprocess_multi_line(comment: &str, indent: usize) -> String {
     let comment = comment
-        .trim_left_matches('/')
-        .trim_left_matches('*')
-        .trim_left_matches('!')
-        .trim_right_matches('/')
-        .trim_right_matches('*')
+        .trim_start_matches('/')
+        .trim_start_matches('*')
+        .trim_start_matches('!')
+        .trim_end_matches('/')
+        .trim_end_matches('*')
         .trim();
 
     let indent = make_indent(indent);
     // Strip any potential `*` characters preceding each line.
     let mut is_first = true;
     let mut lines: Vec<_> = comment.lines()
-        .map(|line| line.trim().trim_left_matches('*').trim())
+        .map(|line| line.trim().trim_start_matches('*').trim())
         .skip_while(|line| line.is_empty()) // Skip the first empty lines.
         .map(|line| {
             let indent = if is_first { "" } else { &*indent };
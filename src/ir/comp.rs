@@ -1008,6 +1008,10 @@ pub struct CompInfo {
     /// look at the virtual methods and ditch this field).
     has_own_virtual_method: bool,
 
+    /// The number of virtual methods, not counting destructors, seen during
+    /// parsing. Some of them may not make it into `methods`.
+    virtual_method_count: usize,
+
     /// Whether this type has destructor.
     has_destructor: bool,
 
@@ -1034,6 +1038,10 @@ pub struct CompInfo {
     /// Used to indicate when a struct has been forward declared. Usually used
     /// in headers so that APIs can't modify them directly.
     is_forward_declaration: bool,
+
+    /// How this type is spelled in C, e.g. `struct foo` or a typedef name, if
+    /// it can be named at all.
+    c_spelling: Option<String>,
 }
 
 impl CompInfo {
@@ -1050,12 +1058,14 @@ impl CompInfo {
             inner_types: vec![],
             inner_vars: vec![],
             has_own_virtual_method: false,
+            virtual_method_count: 0,
             has_destructor: false,
             has_nonempty_base: false,
             has_non_type_template_params: false,
             packed_attr: false,
             found_unknown_attr: false,
             is_forward_declaration: false,
+            c_spelling: None,
         }
     }
 
@@ -1112,6 +1122,12 @@ impl CompInfo {
         return self.has_own_virtual_method;
     }
 
+    /// The number of virtual methods, not counting destructors, we saw when
+    /// parsing this type, whether or not we kept them.
+    pub fn virtual_method_count(&self) -> usize {
+        self.virtual_method_count
+    }
+
     /// Did we see a destructor when parsing this type?
     pub fn has_own_destructor(&self) -> bool {
         self.has_destructor
@@ -1174,6 +1190,24 @@ impl CompInfo {
         debug!("CompInfo::from_ty({:?}, {:?})", kind, cursor);
 
         let mut ci = CompInfo::new(kind);
+
+        // Anonymous types are spelled like "struct (anonymous at foo.h:1:1)".
+        let spelling = ty.spelling();
+        let can_be_named = {
+            let mut words = spelling.split(' ');
+            match (words.next(), words.next(), words.next()) {
+                (Some(name), None, None) |
+                (Some("struct"), Some(name), None) |
+                (Some("union"), Some(name), None) => {
+                    clang::is_valid_identifier(name)
+                }
+                _ => false,
+            }
+        };
+        if can_be_named {
+            ci.c_spelling = Some(spelling);
+        }
+
         ci.is_forward_declaration =
             location.map_or(true, |cur| match cur.kind() {
                 CXCursor_StructDecl |
@@ -1348,6 +1382,9 @@ impl CompInfo {
 
                     ci.has_destructor |= cur.kind() == CXCursor_Destructor;
                     ci.has_own_virtual_method |= is_virtual;
+                    if is_virtual && cur.kind() == CXCursor_CXXMethod {
+                        ci.virtual_method_count += 1;
+                    }
 
                     // This used to not be here, but then I tried generating
                     // stylo bindings with this (without path filters), and
@@ -1528,6 +1565,11 @@ impl CompInfo {
         self.is_forward_declaration
     }
 
+    /// How this type is spelled in C, if it isn't anonymous.
+    pub fn c_spelling(&self) -> Option<&str> {
+        self.c_spelling.as_ref().map(|s| &**s)
+    }
+
     /// Compute this compound structure's bitfield allocation units.
     pub fn compute_bitfield_units(&mut self, ctx: &BindgenContext) {
         self.fields.compute_bitfield_units(ctx);
@@ -1542,7 +1584,9 @@ impl CompInfo {
     ///
     /// Requirements:
     ///     1. Current RustTarget allows for `untagged_union`
-    ///     2. Each field can derive `Copy`
+    ///     2. Each field can derive `Copy`, or the RustTarget allows for
+    ///        `ManuallyDrop` union fields, which non-`Copy` fields get wrapped
+    ///        in
     pub fn can_be_rust_union(&self, ctx: &BindgenContext) -> bool {
         if !ctx.options().rust_features().untagged_union {
             return false;
@@ -1552,6 +1596,10 @@ impl CompInfo {
             return false;
         }
 
+        if ctx.options().rust_features().manually_drop_union {
+            return true;
+        }
+
         self.fields().iter().all(|f| match *f {
             Field::DataMember(ref field_data) => {
                 field_data.ty().can_derive_copy(ctx)
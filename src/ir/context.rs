@@ -18,7 +18,7 @@ use super::traversal::{self, Edge, ItemTraversal};
 use super::ty::{FloatKind, Type, TypeKind};
 use super::function::Function;
 use super::super::time::Timer;
-use BindgenOptions;
+use {BindgenError, BindgenOptions};
 use callbacks::ParseCallbacks;
 use cexpr;
 use clang::{self, Cursor};
@@ -27,7 +27,7 @@ use parse::ClangItemParser;
 use proc_macro2::{Term, Span};
 use std::borrow::Cow;
 use std::cell::Cell;
-use std::collections::{HashMap, HashSet, hash_map};
+use std::collections::{BTreeSet, HashMap, HashSet, hash_map};
 use std::collections::btree_map::{self, BTreeMap};
 use std::iter::IntoIterator;
 use std::mem;
@@ -353,6 +353,9 @@ pub struct BindgenContext {
     /// expression parsing.
     parsed_macros: HashMap<Vec<u8>, cexpr::expr::EvalResult>,
 
+    /// The files clang read while parsing, for the depfile.
+    deps: BTreeSet<String>,
+
     /// The active replacements collected from replaces="xxx" annotations.
     replacements: HashMap<Vec<String>, ItemId>,
 
@@ -375,6 +378,9 @@ pub struct BindgenContext {
     /// Whether a bindgen complex was generated
     generated_bindegen_complex: Cell<bool>,
 
+    /// Whether a bindgen half-precision float was generated
+    generated_bindgen_float16: Cell<bool>,
+
     /// The set of `ItemId`s that are whitelisted. This the very first thing
     /// computed after parsing our IR, and before running any of our analyses.
     whitelisted: Option<ItemSet>,
@@ -532,7 +538,9 @@ fn find_effective_target(clang_args: &[String]) -> (String, bool) {
 
 impl BindgenContext {
     /// Construct the context for the given `options`.
-    pub(crate) fn new(options: BindgenOptions) -> Self {
+    ///
+    /// Fails if libclang could not create a translation unit for the input.
+    pub(crate) fn new(options: BindgenOptions) -> Result<Self, BindgenError> {
         use clang_sys;
 
         // TODO(emilio): Use the CXTargetInfo here when available.
@@ -562,7 +570,9 @@ impl BindgenContext {
                 &clang_args,
                 &options.input_unsaved_files,
                 parse_options,
-            ).expect("TranslationUnit::parse failed")
+            ).ok_or_else(|| {
+                BindgenError::Internal("TranslationUnit::parse failed".into())
+            })?
         };
 
         let target_info = clang::TargetInfo::new(&translation_unit);
@@ -590,6 +600,7 @@ impl BindgenContext {
             semantic_parents: Default::default(),
             currently_parsed_types: vec![],
             parsed_macros: Default::default(),
+            deps: Default::default(),
             replacements: Default::default(),
             collected_typerefs: false,
             in_codegen: false,
@@ -598,6 +609,7 @@ impl BindgenContext {
             target_info,
             options,
             generated_bindegen_complex: Cell::new(false),
+            generated_bindgen_float16: Cell::new(false),
             whitelisted: None,
             codegen_items: None,
             used_template_parameters: None,
@@ -617,7 +629,7 @@ impl BindgenContext {
 
         me.add_item(root_module, None, None);
 
-        me
+        Ok(me)
     }
 
     /// Creates a timer for the current bindgen phase. If time_phases is `true`,
@@ -636,6 +648,57 @@ impl BindgenContext {
         mem::size_of::<*mut ()>()
     }
 
+    /// Returns the architecture of the target for the current translation,
+    /// like `x86_64`, if libclang could tell us.
+    pub fn target_arch(&self) -> Option<&str> {
+        self.target_info
+            .as_ref()
+            .and_then(|ti| ti.triple.split('-').next())
+    }
+
+    /// Whether the target of the current translation is an Apple platform,
+    /// where symbol names get a leading underscore.
+    pub fn target_is_apple(&self) -> bool {
+        self.target_info.as_ref().map_or(
+            cfg!(any(target_os = "macos", target_os = "ios")),
+            |ti| ti.triple.contains("-apple-"),
+        )
+    }
+
+    /// The `core::arch` module, like `x86_64`, that has a type for the given
+    /// well-known vector typedef, like `__m128` or `float32x4_t`, if the
+    /// target's architecture has it.
+    pub fn arch_vector_module(&self, name: &str) -> Option<&'static str> {
+        let features = self.options().rust_features();
+        let arch = match self.target_arch()? {
+            "x86_64" if features.arch_x86_vectors => "x86_64",
+            "i386" | "i486" | "i586" | "i686" if features.arch_x86_vectors => {
+                "x86"
+            }
+            "aarch64" | "arm64" if features.arch_aarch64_vectors => "aarch64",
+            _ => return None,
+        };
+
+        let known = match arch {
+            "aarch64" => match name {
+                "int8x8_t" | "int8x16_t" | "int16x4_t" | "int16x8_t" |
+                "int32x2_t" | "int32x4_t" | "int64x1_t" | "int64x2_t" |
+                "uint8x8_t" | "uint8x16_t" | "uint16x4_t" | "uint16x8_t" |
+                "uint32x2_t" | "uint32x4_t" | "uint64x1_t" | "uint64x2_t" |
+                "float32x2_t" | "float32x4_t" | "float64x1_t" |
+                "float64x2_t" | "poly8x8_t" | "poly8x16_t" | "poly16x4_t" |
+                "poly16x8_t" | "poly64x1_t" | "poly64x2_t" => true,
+                _ => false,
+            },
+            _ => match name {
+                "__m128" | "__m128d" | "__m128i" | "__m256" | "__m256d" |
+                "__m256i" => true,
+                _ => false,
+            },
+        };
+        if known { Some(arch) } else { None }
+    }
+
     /// Get the stack of partially parsed types that we are in the middle of
     /// parsing.
     pub fn currently_parsed_types(&self) -> &[PartialType] {
@@ -662,6 +725,19 @@ impl BindgenContext {
         self.options().parse_callbacks.as_ref().map(|t| &**t)
     }
 
+    /// Record that a file was included while parsing, and let the user know.
+    pub fn include_file(&mut self, filename: &str) {
+        if let Some(cb) = self.parse_callbacks() {
+            cb.include_file(filename);
+        }
+        self.deps.insert(filename.into());
+    }
+
+    /// Get the files clang read while parsing.
+    pub fn deps(&self) -> &BTreeSet<String> {
+        &self.deps
+    }
+
     /// Define a new item.
     ///
     /// This inserts it into the internal items set, and its type into the
@@ -832,7 +908,25 @@ impl BindgenContext {
     // TODO: Move all this syntax crap to other part of the code.
 
     /// Mangles a name so it doesn't conflict with any keyword.
+    ///
+    /// Keywords become raw identifiers, e.g. `r#type`, if those are enabled
+    /// and the target supports them.
     pub fn rust_mangle<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let raw = self.options().raw_identifiers &&
+            self.options().rust_features().raw_identifiers;
+        self.rust_mangle_impl(name, raw)
+    }
+
+    /// Mangles a name so it doesn't conflict with any keyword, without ever
+    /// making it a raw identifier.
+    ///
+    /// This is for names that other identifiers are derived from, e.g. type
+    /// names, which `r#` can't be prepended to.
+    pub fn rust_mangle_non_raw<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.rust_mangle_impl(name, false)
+    }
+
+    fn rust_mangle_impl<'a>(&self, name: &'a str, raw: bool) -> Cow<'a, str> {
         if name.contains("@") ||
             name.contains("?") ||
             name.contains("$") ||
@@ -894,6 +988,15 @@ impl BindgenContext {
                 _ => false,
             }
         {
+            let can_be_raw = match name {
+                "Self" | "self" | "super" | "crate" | "_" => false,
+                _ => !name.contains("@") && !name.contains("?") &&
+                    !name.contains("$"),
+            };
+            if raw && can_be_raw {
+                return Cow::Owned(format!("r#{}", name));
+            }
+
             let mut s = name.to_owned();
             s = s.replace("@", "_");
             s = s.replace("?", "_");
@@ -1453,7 +1556,7 @@ impl BindgenContext {
 
     fn build_root_module(id: ItemId) -> Item {
         let module = Module::new(Some("root".into()), ModuleKind::Normal);
-        Item::new(id, None, None, id, ItemKind::Module(module))
+        Item::new(id, None, None, id, ItemKind::Module(module), None)
     }
 
     /// Get the root module.
@@ -1753,6 +1856,7 @@ impl BindgenContext {
                             None,
                             self.current_module.into(),
                             ItemKind::Type(sub_ty),
+                            None,
                         );
 
                         // Bypass all the validations in add_item explicitly.
@@ -1817,6 +1921,7 @@ impl BindgenContext {
             None,
             self.current_module.into(),
             ItemKind::Type(ty),
+            None,
         );
 
         // Bypass all the validations in add_item explicitly.
@@ -1972,6 +2077,7 @@ impl BindgenContext {
             None,
             parent_id.unwrap_or(self.current_module.into()),
             ItemKind::Type(ty),
+            None,
         );
         self.add_builtin_item(item);
         with_id.as_type_id_unchecked()
@@ -2010,6 +2116,8 @@ impl BindgenContext {
             CXType_ULongLong => TypeKind::Int(IntKind::ULongLong),
             CXType_Int128 => TypeKind::Int(IntKind::I128),
             CXType_UInt128 => TypeKind::Int(IntKind::U128),
+            CXType_Half |
+            CXType_Float16 => TypeKind::Float(FloatKind::Float16),
             CXType_Float => TypeKind::Float(FloatKind::Float),
             CXType_Double => TypeKind::Float(FloatKind::Double),
             CXType_LongDouble => TypeKind::Float(FloatKind::LongDouble),
@@ -2018,6 +2126,8 @@ impl BindgenContext {
                 let float_type =
                     ty.elem_type().expect("Not able to resolve complex type?");
                 let float_kind = match float_type.kind() {
+                    CXType_Half |
+                    CXType_Float16 => FloatKind::Float16,
                     CXType_Float => FloatKind::Float,
                     CXType_Double => FloatKind::Double,
                     CXType_LongDouble => FloatKind::LongDouble,
@@ -2041,7 +2151,7 @@ impl BindgenContext {
         let ty = Type::new(Some(spelling), layout, type_kind, is_const);
         let id = self.next_item_id();
         let item =
-            Item::new(id, None, None, self.root_module.into(), ItemKind::Type(ty));
+            Item::new(id, None, None, self.root_module.into(), ItemKind::Type(ty), None);
         self.add_builtin_item(item);
         Some(id.as_type_id_unchecked())
     }
@@ -2103,7 +2213,7 @@ impl BindgenContext {
         }
     }
 
-    /// Is the item with the given `name` blacklisted? Or is the item with the given
+    /// Is the type with the given `name` blacklisted? Or is the type with the given
     /// `name` and `id` replaced by another type, and effectively blacklisted?
     pub fn blacklisted_by_name<Id: Into<ItemId>>(&self, path: &[String], id: Id) -> bool {
         let id = id.into();
@@ -2220,6 +2330,7 @@ impl BindgenContext {
             None,
             self.current_module.into(),
             ItemKind::Module(module),
+            None,
         );
 
         let module_id = module.id().as_module_id_unchecked();
@@ -2280,7 +2391,8 @@ impl BindgenContext {
                     // game.
                     if self.options().whitelisted_types.is_empty() &&
                         self.options().whitelisted_functions.is_empty() &&
-                        self.options().whitelisted_vars.is_empty() {
+                        self.options().whitelisted_vars.is_empty() &&
+                        self.options().whitelisted_files.is_empty() {
                             return true;
                         }
 
@@ -2290,6 +2402,14 @@ impl BindgenContext {
                         return true;
                     }
 
+                    // Items declared in a whitelisted file are whitelisted,
+                    // whatever their name.
+                    if let Some(file_name) = item.file_name() {
+                        if self.options().whitelisted_files.matches(&file_name) {
+                            return true;
+                        }
+                    }
+
                     let name = item.canonical_path(self)[1..].join("::");
                     debug!("whitelisted_items: testing {:?}", name);
                     match *item.kind() {
@@ -2394,6 +2514,16 @@ impl BindgenContext {
         self.generated_bindegen_complex.get()
     }
 
+    /// Call if a bindgen half-precision float is generated
+    pub fn generated_bindgen_float16(&self) {
+        self.generated_bindgen_float16.set(true)
+    }
+
+    /// Whether we need to generate the bindgen half-precision float type
+    pub fn need_bindgen_float16_type(&self) -> bool {
+        self.generated_bindgen_float16.get()
+    }
+
     /// Compute whether we can derive debug.
     fn compute_cannot_derive_debug(&mut self) {
         let _t = self.timer("compute_cannot_derive_debug");
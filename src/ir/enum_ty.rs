@@ -149,6 +149,18 @@ impl Enum {
                 }))
     }
 
+    /// Whether the enum should be a newtype
+    pub fn is_newtype(&self, ctx: &BindgenContext, item: &Item) -> bool {
+        let path = item.canonical_path(ctx);
+        let enum_ty = item.expect_type();
+
+        ctx.options().newtype_enums.matches(&path[1..].join("::")) ||
+            (enum_ty.name().is_none() &&
+                self.variants().iter().any(|v| {
+                    ctx.options().newtype_enums.matches(&v.name())
+                }))
+    }
+
     /// Whether the enum should be an constified enum module
     pub fn is_constified_enum_module(
         &self,
@@ -165,6 +177,26 @@ impl Enum {
                 }))
     }
 
+    /// Whether the enum should be a non-exhaustive Rust enum
+    pub fn is_rustified_non_exhaustive_enum(
+        &self,
+        ctx: &BindgenContext,
+        item: &Item,
+    ) -> bool {
+        let path = item.canonical_path(ctx);
+        let enum_ty = item.expect_type();
+
+        ctx.options()
+            .rustified_non_exhaustive_enums
+            .matches(&path[1..].join("::")) ||
+            (enum_ty.name().is_none() &&
+                self.variants().iter().any(|v| {
+                    ctx.options()
+                        .rustified_non_exhaustive_enums
+                        .matches(&v.name())
+                }))
+    }
+
     /// Whether the enum should be a Rust enum
     pub fn is_rustified_enum(&self, ctx: &BindgenContext, item: &Item) -> bool {
         let path = item.canonical_path(ctx);
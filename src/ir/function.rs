@@ -67,6 +67,98 @@ pub enum Linkage {
     Internal
 }
 
+/// The C spelling of a function prototype, kept for functions whose symbol is
+/// not exported so that an exported wrapper can be written for them.
+#[derive(Debug, Clone)]
+pub struct CPrototype {
+    /// The spelling of the return type, or `None` for `void`.
+    return_type: Option<String>,
+
+    /// The spelling of each argument type.
+    argument_types: Vec<String>,
+}
+
+impl CPrototype {
+    /// Get the prototype of the function pointed to by `cursor`, if it can be
+    /// forwarded to from a wrapper.
+    fn from_cursor(cursor: &clang::Cursor) -> Option<Self> {
+        let ty = cursor.cur_type();
+
+        // There's no portable way to forward variadic arguments.
+        if ty.is_variadic() {
+            return None;
+        }
+
+        let return_type = match ty.ret_type() {
+            Some(ref ret) if ret.kind() == clang_sys::CXType_Void => None,
+            Some(ret) => Some(Self::spell(&ret)),
+            None => return None,
+        };
+
+        let argument_types = match cursor.args() {
+            Some(args) => args.iter()
+                .map(|arg| Self::spell(&arg.cur_type()))
+                .collect(),
+            None => return None,
+        };
+
+        Some(CPrototype {
+            return_type,
+            argument_types,
+        })
+    }
+
+    /// Spell a type so that it can be followed by a declarator name. Function
+    /// pointers and arrays spell the name in the middle of the type, so those
+    /// go through `__typeof__`.
+    fn spell(ty: &clang::Type) -> String {
+        let spelling = ty.spelling();
+        if spelling.contains('(') || spelling.contains('[') {
+            format!("__typeof__({})", spelling)
+        } else {
+            spelling
+        }
+    }
+
+    /// Write the C definition of an exported function called `wrapper_name`
+    /// that forwards its arguments to `name`.
+    pub fn wrapper(&self, name: &str, wrapper_name: &str) -> String {
+        let params = if self.argument_types.is_empty() {
+            "void".to_owned()
+        } else {
+            self.argument_types
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| format!("{} arg_{}", ty, i + 1))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let args = (1..self.argument_types.len() + 1)
+            .map(|i| format!("arg_{}", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match self.return_type {
+            Some(ref ret) => format!(
+                "{} {}({}) {{ return {}({}); }}",
+                ret,
+                wrapper_name,
+                params,
+                name,
+                args
+            ),
+            None => format!(
+                "void {}({}) {{ {}({}); }}",
+                wrapper_name,
+                params,
+                name,
+                args
+            ),
+        }
+    }
+}
+
 /// A function declaration, with a signature, arguments, and argument names.
 ///
 /// The argument names vector must be the same length as the ones in the
@@ -90,6 +182,10 @@ pub struct Function {
 
     /// The linkage of the function.
     linkage: Linkage,
+
+    /// The C prototype of the function, if its symbol isn't exported and an
+    /// exported wrapper should be generated for it.
+    c_prototype: Option<CPrototype>,
 }
 
 impl Function {
@@ -109,6 +205,7 @@ impl Function {
             comment,
             kind,
             linkage,
+            c_prototype: None,
         }
     }
 
@@ -137,6 +234,12 @@ impl Function {
         self.linkage
     }
 
+    /// Get the C prototype to write a wrapper with, if this function needs
+    /// one.
+    pub fn c_prototype(&self) -> Option<&CPrototype> {
+        self.c_prototype.as_ref()
+    }
+
 }
 
 impl DotAttributes for Function {
@@ -221,6 +324,10 @@ pub struct FunctionSig {
     /// Whether this function is variadic.
     is_variadic: bool,
 
+    /// Whether the result of this function should be used, as per its
+    /// `warn_unused_result` attribute.
+    must_use: bool,
+
     /// The ABI of this function.
     abi: Abi,
 }
@@ -310,12 +417,14 @@ impl FunctionSig {
         return_type: TypeId,
         arguments: Vec<(Option<String>, TypeId)>,
         is_variadic: bool,
+        must_use: bool,
         abi: Abi,
     ) -> Self {
         FunctionSig {
             return_type: return_type,
             argument_types: arguments,
             is_variadic: is_variadic,
+            must_use: must_use,
             abi: abi,
         }
     }
@@ -387,6 +496,15 @@ impl FunctionSig {
             }
         };
 
+        // clang-sys doesn't know about `CXCursor_WarnUnusedResultAttr` yet,
+        // and libclang exposes it from 9.0 on.
+        #[allow(non_upper_case_globals)]
+        const CXCursor_WarnUnusedResultAttr: CXCursorKind = 440;
+        let must_use = ctx.options().enable_function_attribute_detection &&
+            cursor.has_simple_attr(
+                "warn_unused_result",
+                CXCursor_WarnUnusedResultAttr,
+            );
         let is_method = cursor.kind() == CXCursor_CXXMethod;
         let is_constructor = cursor.kind() == CXCursor_Constructor;
         let is_destructor = cursor.kind() == CXCursor_Destructor;
@@ -449,7 +567,7 @@ impl FunctionSig {
             warn!("Unknown calling convention: {:?}", call_conv);
         }
 
-        Ok(Self::new(ret.into(), args, ty.is_variadic(), abi))
+        Ok(Self::new(ret.into(), args, ty.is_variadic(), must_use, abi))
     }
 
     /// Get this function signature's return type.
@@ -457,6 +575,11 @@ impl FunctionSig {
         self.return_type
     }
 
+    /// Whether the result of this function should be used.
+    pub fn must_use(&self) -> bool {
+        self.must_use
+    }
+
     /// Get this function signature's argument (name, type) pairs.
     pub fn argument_types(&self) -> &[(Option<String>, TypeId)] {
         &self.argument_types
@@ -520,6 +643,7 @@ impl ClangSubItemParser for Function {
         }
 
         if !context.options().generate_inline_functions &&
+            !context.options().wrap_static_fns &&
             cursor.is_inlined_function()
         {
             return Err(ParseError::Continue);
@@ -560,7 +684,26 @@ impl ClangSubItemParser for Function {
 
         let comment = cursor.raw_comment();
 
-        let function = Self::new(name, mangled_name, sig, comment, kind, linkage);
+        let needs_wrapper = match linkage {
+            Linkage::Internal => true,
+            Linkage::External => cursor.is_inlined_function(),
+        };
+
+        let mut function = Self::new(name, mangled_name, sig, comment, kind, linkage);
+        if context.options().wrap_static_fns && needs_wrapper &&
+            kind == FunctionKind::Function &&
+            cursor.semantic_parent().kind() == CXCursor_TranslationUnit
+        {
+            function.c_prototype = CPrototype::from_cursor(&cursor);
+        }
+
+        // Inline functions we can't wrap are skipped as usual.
+        if !context.options().generate_inline_functions &&
+            cursor.is_inlined_function() &&
+            function.c_prototype.is_none()
+        {
+            return Err(ParseError::Continue);
+        }
         Ok(ParseResult::New(function, Some(cursor)))
     }
 }
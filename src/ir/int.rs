@@ -125,6 +125,34 @@ impl IntKind {
         })
     }
 
+    /// The Rust primitive a well-known `<stdint.h>` or `<stddef.h>` typedef,
+    /// like `uint32_t` or `size_t`, stands for, if any.
+    ///
+    /// Pointer-sized typedefs map to `usize` and `isize`, which have no known
+    /// size.
+    pub fn from_stdint_name(name: &str) -> Option<Self> {
+        use self::IntKind::*;
+        Some(match name {
+            "int8_t" => I8,
+            "uint8_t" => U8,
+            "int16_t" => I16,
+            "uint16_t" => U16,
+            "int32_t" => I32,
+            "uint32_t" => U32,
+            "int64_t" => I64,
+            "uint64_t" => U64,
+            "uintptr_t" | "size_t" => Custom {
+                name: "usize",
+                is_signed: false,
+            },
+            "intptr_t" | "ptrdiff_t" | "ssize_t" => Custom {
+                name: "isize",
+                is_signed: true,
+            },
+            _ => return None,
+        })
+    }
+
     /// Whether this type's signedness matches the value.
     pub fn signedness_matches(&self, val: i64) -> bool {
         val >= 0 || self.is_signed()
@@ -17,6 +17,8 @@ use super::super::codegen::CONSTIFIED_ENUM_MODULE_REPR_NAME;
 use super::template::{AsTemplateParam, TemplateParameters};
 use super::traversal::{EdgeKind, Trace, Tracer};
 use super::ty::{Type, TypeKind};
+use super::var::VarType;
+use callbacks;
 use clang;
 use clang_sys;
 use parse::{ClangItemParser, ClangSubItemParser, ParseError, ParseResult};
@@ -303,6 +305,11 @@ impl Trace for Item {
             }
             ItemKind::Var(ref var) => {
                 tracer.visit_kind(var.ty().into(), EdgeKind::VarType);
+                if let Some(&VarType::FunctionMacro(ref m)) = var.val() {
+                    for &(_, _, ty) in m.params() {
+                        tracer.visit_kind(ty.into(), EdgeKind::VarType);
+                    }
+                }
             }
             ItemKind::Module(_) => {
                 // Module -> children edges are "weak", and we do not want to
@@ -418,6 +425,8 @@ pub struct Item {
     parent_id: ItemId,
     /// The item kind.
     kind: ItemKind,
+    /// The source location of the item.
+    location: Option<clang::SourceLocation>,
 }
 
 impl AsRef<ItemId> for Item {
@@ -434,6 +443,7 @@ impl Item {
         annotations: Option<Annotations>,
         parent_id: ItemId,
         kind: ItemKind,
+        location: Option<clang::SourceLocation>,
     ) -> Self {
         debug_assert!(id != parent_id || kind.is_module());
         Item {
@@ -445,6 +455,7 @@ impl Item {
             comment: comment,
             annotations: annotations.unwrap_or_default(),
             kind: kind,
+            location: location,
         }
     }
 
@@ -457,7 +468,11 @@ impl Item {
         let ty = Opaque::from_clang_ty(ty);
         let kind = ItemKind::Type(ty);
         let parent = ctx.root_module().into();
-        ctx.add_item(Item::new(with_id, None, None, parent, kind), None, None);
+        ctx.add_item(
+            Item::new(with_id, None, None, parent, kind, None),
+            None,
+            None,
+        );
         with_id.as_type_id_unchecked()
     }
 
@@ -623,6 +638,19 @@ impl Item {
         &self.annotations
     }
 
+    /// Get this item's source location, if we know it.
+    pub fn location(&self) -> Option<&clang::SourceLocation> {
+        self.location.as_ref()
+    }
+
+    /// Get the name of the file this item was declared in, if we know it.
+    pub fn file_name(&self) -> Option<String> {
+        self.location.as_ref().and_then(|location| {
+            let (file, _, _, _) = location.location();
+            file.name()
+        })
+    }
+
     /// Whether this item should be blacklisted.
     ///
     /// This may be due to either annotations or to other kind of configuration.
@@ -631,8 +659,27 @@ impl Item {
             ctx.in_codegen_phase(),
             "You're not supposed to call this yet"
         );
-        self.annotations.hide() ||
-            ctx.blacklisted_by_name(&self.canonical_path(ctx), self.id)
+        if self.annotations.hide() {
+            return true;
+        }
+
+        if let Some(file_name) = self.file_name() {
+            if ctx.options().blacklisted_files.matches(&file_name) {
+                return true;
+            }
+        }
+
+        let path = self.canonical_path(ctx);
+        let name = path[1..].join("::");
+        ctx.options().blacklisted_items.matches(&name) ||
+            match self.kind {
+                ItemKind::Type(..) => ctx.blacklisted_by_name(&path, self.id),
+                ItemKind::Function(..) => {
+                    ctx.options().blacklisted_functions.matches(&name)
+                }
+                ItemKind::Var(..) => ctx.options().blacklisted_vars.matches(&name),
+                ItemKind::Module(..) => false,
+            }
     }
 
     /// Is this a reference to another type?
@@ -858,7 +905,26 @@ impl Item {
 
         let name = names.join("_");
 
-        ctx.rust_mangle(&name).into_owned()
+        let kind = match *target.kind() {
+            ItemKind::Module(..) => callbacks::ItemKind::Module,
+            ItemKind::Type(..) => callbacks::ItemKind::Type,
+            ItemKind::Function(..) => callbacks::ItemKind::Function,
+            ItemKind::Var(..) => callbacks::ItemKind::Var,
+        };
+        let name = ctx.parse_callbacks()
+            .and_then(|cb| cb.item_name(&name, kind))
+            .unwrap_or(name);
+
+        // Other identifiers are derived from type and module names, so those
+        // can't be raw identifiers.
+        match kind {
+            callbacks::ItemKind::Module | callbacks::ItemKind::Type => {
+                ctx.rust_mangle_non_raw(&name).into_owned()
+            }
+            callbacks::ItemKind::Function | callbacks::ItemKind::Var => {
+                ctx.rust_mangle(&name).into_owned()
+            }
+        }
     }
 
     /// The exposed id that represents an unique id among the siblings of a
@@ -1190,7 +1256,7 @@ impl ClangItemParser for Item {
         let id = ctx.next_item_id();
         let module = ctx.root_module().into();
         ctx.add_item(
-            Item::new(id, None, None, module, ItemKind::Type(ty)),
+            Item::new(id, None, None, module, ItemKind::Type(ty), None),
             None,
             None,
         );
@@ -1226,7 +1292,8 @@ impl ClangItemParser for Item {
 
                         ctx.add_item(Item::new(id, comment, annotations,
                                                relevant_parent_id,
-                                               ItemKind::$what(item)),
+                                               ItemKind::$what(item),
+                                               Some(cursor.location())),
                                          declaration,
                                          Some(cursor));
                         return Ok(id);
@@ -1296,12 +1363,20 @@ impl ClangItemParser for Item {
             // We whitelist cursors here known to be unhandled, to prevent being
             // too noisy about this.
             match cursor.kind() {
+                CXCursor_InclusionDirective => {
+                    match cursor.get_included_file_name() {
+                        Some(file) => ctx.include_file(&file),
+                        None => warn!(
+                            "Inclusion of a nameless file in {:?}",
+                            cursor
+                        ),
+                    }
+                }
                 CXCursor_MacroDefinition |
                 CXCursor_MacroExpansion |
                 CXCursor_UsingDeclaration |
                 CXCursor_UsingDirective |
-                CXCursor_StaticAssert |
-                CXCursor_InclusionDirective => {
+                CXCursor_StaticAssert => {
                     debug!(
                         "Unhandled cursor kind {:?}: {:?}",
                         cursor.kind(),
@@ -1396,6 +1471,7 @@ impl ClangItemParser for Item {
                 None,
                 parent_id.unwrap_or(current_module.into()),
                 ItemKind::Type(Type::new(None, None, kind, is_const)),
+                None,
             ),
             Some(clang::Cursor::null()),
             None,
@@ -1521,6 +1597,7 @@ impl ClangItemParser for Item {
                         annotations,
                         relevant_parent_id,
                         ItemKind::Type(item),
+                        declaration.as_ref().map(clang::Cursor::location),
                     ),
                     declaration,
                     Some(location),
@@ -1749,6 +1826,7 @@ impl ClangItemParser for Item {
             None,
             parent,
             ItemKind::Type(Type::named(name)),
+            Some(location.location()),
         );
         ctx.add_type_param(item, definition);
         Some(id.as_type_id_unchecked())
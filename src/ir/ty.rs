@@ -153,11 +153,13 @@ impl Type {
             TypeKind::NullPtr |
             TypeKind::Function(..) |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
             TypeKind::Reference(..) |
             TypeKind::Pointer(..) |
             TypeKind::BlockPointer |
             TypeKind::Int(..) |
             TypeKind::Float(..) |
+            TypeKind::Atomic(..) |
             TypeKind::TypeParam => true,
             _ => false,
         }
@@ -229,6 +231,21 @@ impl Type {
         }
     }
 
+    /// Is this a vector typedef, like `__m128`, that we map to its
+    /// `core::arch` type? Those only implement `Copy`, `Clone` and `Debug`.
+    pub fn is_arch_vector(&self, ctx: &BindgenContext) -> bool {
+        match self.kind {
+            TypeKind::Alias(..) => {}
+            _ => return false,
+        }
+        match *self.canonical_type(ctx).kind() {
+            TypeKind::Vector(..) => {}
+            _ => return false,
+        }
+        self.name()
+            .map_or(false, |name| ctx.arch_vector_module(name).is_some())
+    }
+
     /// What is the layout of this type?
     pub fn layout(&self, ctx: &BindgenContext) -> Option<Layout> {
         self.layout.or_else(|| {
@@ -286,6 +303,9 @@ impl Type {
             TypeKind::Array(inner, length) => {
                 Some((inner, format!("array{}", length).into()))
             }
+            TypeKind::Vector(inner, count) => {
+                Some((inner, format!("vector{}", count).into()))
+            }
             _ => None,
         };
         if let Some((inner, prefix)) = name_info {
@@ -320,6 +340,8 @@ impl Type {
         match self.kind {
             TypeKind::TypeParam |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
+            TypeKind::Atomic(..) |
             TypeKind::Comp(..) |
             TypeKind::Opaque |
             TypeKind::Int(..) |
@@ -358,6 +380,7 @@ impl Type {
             TypeKind::Function(..) |
             TypeKind::Pointer(..) |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
             TypeKind::Reference(..) |
             TypeKind::TemplateInstantiation(..) |
             TypeKind::ResolvedTypeRef(..) => true,
@@ -472,6 +495,8 @@ impl TypeKind {
             TypeKind::Alias(..) => "Alias",
             TypeKind::TemplateAlias(..) => "TemplateAlias",
             TypeKind::Array(..) => "Array",
+            TypeKind::Vector(..) => "Vector",
+            TypeKind::Atomic(..) => "Atomic",
             TypeKind::Function(..) => "Function",
             TypeKind::Enum(..) => "Enum",
             TypeKind::Pointer(..) => "Pointer",
@@ -565,6 +590,8 @@ impl TemplateParameters for TypeKind {
             TypeKind::Float(_) |
             TypeKind::Complex(_) |
             TypeKind::Array(..) |
+            TypeKind::Vector(..) |
+            TypeKind::Atomic(..) |
             TypeKind::Function(_) |
             TypeKind::Enum(_) |
             TypeKind::Pointer(_) |
@@ -583,6 +610,8 @@ impl TemplateParameters for TypeKind {
 /// The kind of float this type represents.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum FloatKind {
+    /// A `_Float16` or `__fp16`.
+    Float16,
     /// A `float`.
     Float,
     /// A `double`.
@@ -593,6 +622,165 @@ pub enum FloatKind {
     Float128,
 }
 
+/// The kind of a C11 `_Atomic` type which has a counterpart in
+/// `core::sync::atomic`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AtomicKind {
+    /// An `_Atomic(_Bool)`.
+    Bool,
+    /// An atomic integer.
+    Int(IntKind),
+    /// An atomic pointer to the given type.
+    Ptr(TypeId),
+}
+
+impl AtomicKind {
+    /// Get the kind of an `_Atomic` type, given the canonical spelling of the
+    /// type it makes atomic and its layout, if Rust has an atomic type with
+    /// the same layout.
+    ///
+    /// Enums and pointees are looked up in the type references under
+    /// `location`, since we can't get at the value type itself. The value
+    /// type as written, if any, tells `size_t` and friends apart.
+    fn from_value_spelling(
+        spelling: &str,
+        written_spelling: Option<&str>,
+        layout: Layout,
+        location: &Cursor,
+        ctx: &mut BindgenContext,
+    ) -> Option<Self> {
+        use clang_sys::CXType_Enum;
+
+        // Rust's atomics are always aligned to their size.
+        if layout.align != layout.size {
+            return None;
+        }
+
+        if spelling.ends_with('*') {
+            if layout.size != ctx.target_pointer_size() {
+                return None;
+            }
+            let pointee = &spelling[..spelling.len() - 1];
+            return Some(AtomicKind::Ptr(atomic_pointee(pointee, location, ctx)));
+        }
+
+        if spelling == "_Bool" || spelling == "bool" {
+            return if layout.size == 1 { Some(AtomicKind::Bool) } else { None };
+        }
+
+        let stdint_kind = written_spelling.and_then(IntKind::from_stdint_name);
+        let kind = match stdint_kind.or_else(|| {
+            int_kind_from_canonical_spelling(spelling)
+        }) {
+            Some(kind) => kind,
+            None => {
+                let ty = find_type_ref(spelling, location)?;
+                if ty.canonical_type().kind() != CXType_Enum {
+                    return None;
+                }
+                let int_ty = ty.canonical_type().declaration().enum_type()?;
+                int_kind_from_canonical_spelling(
+                    &int_ty.canonical_type().spelling(),
+                )?
+            }
+        };
+
+        let size = kind.known_size().unwrap_or(match kind {
+            IntKind::Custom { .. } => ctx.target_pointer_size(),
+            _ => layout.size,
+        });
+        match size {
+            1 | 2 | 4 | 8 if size == layout.size => Some(AtomicKind::Int(kind)),
+            _ => None,
+        }
+    }
+}
+
+/// Get the integer kind of a builtin integer type, given its canonical
+/// spelling.
+fn int_kind_from_canonical_spelling(spelling: &str) -> Option<IntKind> {
+    Some(match spelling {
+        "signed char" => IntKind::SChar,
+        "unsigned char" => IntKind::UChar,
+        "short" => IntKind::Short,
+        "unsigned short" => IntKind::UShort,
+        "int" => IntKind::Int,
+        "unsigned int" => IntKind::UInt,
+        "long" => IntKind::Long,
+        "unsigned long" => IntKind::ULong,
+        "long long" => IntKind::LongLong,
+        "unsigned long long" => IntKind::ULongLong,
+        "char16_t" => IntKind::U16,
+        "char32_t" => IntKind::U32,
+        // The signedness of `char` depends on the target, and we can't tell
+        // it from the spelling.
+        _ => return None,
+    })
+}
+
+/// Find a type referenced under `location` whose canonical spelling is the
+/// given one.
+fn find_type_ref(spelling: &str, location: &Cursor) -> Option<clang::Type> {
+    use clang_sys::*;
+
+    let mut found = None;
+    location.visit(|cur| {
+        if cur.kind() == CXCursor_TypeRef &&
+            cur.cur_type().canonical_type().spelling() == spelling
+        {
+            found = Some(cur.cur_type());
+            return CXChildVisit_Break;
+        }
+        CXChildVisit_Recurse
+    });
+    found
+}
+
+/// Get the pointee of an atomic pointer, given its canonical spelling.
+///
+/// Pointees we can't figure out, like `char`, are `void`.
+fn atomic_pointee(
+    spelling: &str,
+    location: &Cursor,
+    ctx: &mut BindgenContext,
+) -> TypeId {
+    let mut spelling = spelling.trim();
+    let mut is_const = false;
+    loop {
+        if spelling.starts_with("const ") {
+            is_const = true;
+            spelling = &spelling["const ".len()..];
+        } else if spelling.ends_with(" const") {
+            is_const = true;
+            spelling = &spelling[..spelling.len() - " const".len()];
+        } else if spelling.starts_with("volatile ") {
+            spelling = &spelling["volatile ".len()..];
+        } else if spelling.ends_with(" volatile") {
+            spelling = &spelling[..spelling.len() - " volatile".len()];
+        } else {
+            break;
+        }
+    }
+
+    if spelling.ends_with('*') {
+        let pointee = &spelling[..spelling.len() - 1];
+        let pointee = atomic_pointee(pointee, location, ctx);
+        return Item::builtin_type(TypeKind::Pointer(pointee), is_const, ctx);
+    }
+
+    if let Some(ty) = find_type_ref(spelling, location) {
+        return Item::from_ty_or_ref(ty, *location, None, ctx);
+    }
+
+    let kind = match spelling {
+        "float" => TypeKind::Float(FloatKind::Float),
+        "double" => TypeKind::Float(FloatKind::Double),
+        _ => int_kind_from_canonical_spelling(spelling)
+            .map_or(TypeKind::Void, TypeKind::Int),
+    };
+    Item::builtin_type(kind, is_const, ctx)
+}
+
 /// The different kinds of types that we can parse.
 #[derive(Debug)]
 pub enum TypeKind {
@@ -630,6 +818,13 @@ pub enum TypeKind {
     /// An array of a type and a length.
     Array(TypeId, usize),
 
+    /// A SIMD vector of a type and a number of elements, like `__m128` or a
+    /// type declared with `__attribute__((vector_size(N)))`.
+    Vector(TypeId, usize),
+
+    /// A C11 `_Atomic` type.
+    Atomic(AtomicKind),
+
     /// A function type, with a given signature.
     Function(FunctionSig),
 
@@ -764,6 +959,23 @@ impl Type {
             );
         }
 
+        if let Some(value) = ty.atomic_value_spelling() {
+            let written = ty.atomic_value_written_spelling();
+            let kind = layout
+                .and_then(|l| {
+                    AtomicKind::from_value_spelling(
+                        &value,
+                        written.as_ref().map(|w| &**w),
+                        l,
+                        &location,
+                        ctx,
+                    )
+                })
+                .map_or(TypeKind::Opaque, TypeKind::Atomic);
+            let ty = Type::new(None, layout, kind, ty.is_const());
+            return Ok(ParseResult::New(ty, None));
+        }
+
         let kind = if location.kind() == CXCursor_TemplateRef ||
             (ty.template_args().is_some() && ty_kind != CXType_Typedef)
         {
@@ -1112,10 +1324,32 @@ impl Type {
                     TypeKind::Function(signature)
                 }
                 CXType_Typedef => {
-                    let inner = cursor.typedef_type().expect("Not valid Type?");
-                    let inner =
-                        Item::from_ty_or_ref(inner, location, None, ctx);
-                    TypeKind::Alias(inner)
+                    let stdint_kind = if ctx.options().stdint_primitives {
+                        IntKind::from_stdint_name(&name).filter(|kind| {
+                            let size = kind.known_size()
+                                .unwrap_or(ctx.target_pointer_size());
+                            layout.map_or(false, |l| l.size == size)
+                        })
+                    } else {
+                        None
+                    };
+
+                    match stdint_kind {
+                        // Don't even look at the inner type, so that the
+                        // aliases it's defined through aren't generated.
+                        Some(kind) => TypeKind::Int(kind),
+                        None => {
+                            let inner = cursor.typedef_type()
+                                .expect("Not valid Type?");
+                            let inner = Item::from_ty_or_ref(
+                                inner,
+                                location,
+                                None,
+                                ctx,
+                            );
+                            TypeKind::Alias(inner)
+                        }
+                    }
                 }
                 CXType_Enum => {
                     let enum_ = Enum::from_ty(ty, ctx).expect("Not an enum?");
@@ -1148,12 +1382,6 @@ impl Type {
 
                     TypeKind::Comp(complex)
                 }
-                // FIXME: We stub vectors as arrays since in 99% of the cases the
-                // layout is going to be correct, and there's no way we can generate
-                // vector types properly in Rust for now.
-                //
-                // That being said, that should be fixed eventually.
-                CXType_Vector |
                 CXType_ConstantArray => {
                     let inner = Item::from_ty(
                         ty.elem_type().as_ref().unwrap(),
@@ -1163,6 +1391,15 @@ impl Type {
                     ).expect("Not able to resolve array element?");
                     TypeKind::Array(inner, ty.num_elements().unwrap())
                 }
+                CXType_Vector => {
+                    let inner = Item::from_ty(
+                        ty.elem_type().as_ref().unwrap(),
+                        location,
+                        None,
+                        ctx,
+                    ).expect("Not able to resolve vector element?");
+                    TypeKind::Vector(inner, ty.num_elements().unwrap())
+                }
                 CXType_Elaborated => {
                     return Self::from_clang_ty(
                         potential_id,
@@ -1213,7 +1450,9 @@ impl Trace for Type {
         match *self.kind() {
             TypeKind::Pointer(inner) |
             TypeKind::Reference(inner) |
+            TypeKind::Atomic(AtomicKind::Ptr(inner)) |
             TypeKind::Array(inner, _) |
+            TypeKind::Vector(inner, _) |
             TypeKind::Alias(inner) |
             TypeKind::ResolvedTypeRef(inner) => {
                 tracer.visit_kind(inner.into(), EdgeKind::TypeReference);
@@ -1254,6 +1493,7 @@ impl Trace for Type {
             TypeKind::Int(_) |
             TypeKind::Float(_) |
             TypeKind::Complex(_) |
+            TypeKind::Atomic(_) |
             TypeKind::ObjCId |
             TypeKind::ObjCSel |
             TypeKind::BlockPointer => {}
@@ -1,6 +1,6 @@
 //! Intermediate representation of variables.
 
-use callbacks::MacroParsingBehavior;
+use callbacks::{MacroParsingBehavior, ParseCallbacks};
 use super::context::{BindgenContext, TypeId};
 use super::dot::DotAttributes;
 use super::function::cursor_mangling;
@@ -9,9 +9,11 @@ use super::item::Item;
 use super::ty::{FloatKind, TypeKind};
 use cexpr;
 use clang;
+use clang_sys::CXToken_Punctuation;
 use parse::{ClangItemParser, ClangSubItemParser, ParseError, ParseResult};
 use std::io;
 use std::num::Wrapping;
+use std::str;
 
 /// The type for a constant variable.
 #[derive(Debug)]
@@ -26,6 +28,48 @@ pub enum VarType {
     Char(u8),
     /// A string, not necessarily well-formed utf-8.
     String(Vec<u8>),
+    /// A function-like macro, computing a value of the variable's type from
+    /// its parameters.
+    FunctionMacro(FunctionMacro),
+}
+
+/// A function-like macro whose body is integer arithmetic over its
+/// parameters, so that it can be translated into Rust.
+#[derive(Debug)]
+pub struct FunctionMacro {
+    /// The names, integer kinds and types of the parameters.
+    params: Vec<(String, IntKind, TypeId)>,
+    /// The integer kind the body is computed in.
+    kind: IntKind,
+    /// The body, already translated into Rust.
+    body: Vec<MacroToken>,
+}
+
+/// A token of the body of a translated function-like macro.
+#[derive(Debug)]
+pub enum MacroToken {
+    /// A use of the parameter with the given index.
+    Param(usize),
+    /// Any other token, as Rust source.
+    Raw(String),
+}
+
+impl FunctionMacro {
+    /// Get the names, integer kinds and types of the parameters.
+    pub fn params(&self) -> &[(String, IntKind, TypeId)] {
+        &self.params
+    }
+
+    /// Get the integer kind the body is computed in, which is also the kind of
+    /// the result.
+    pub fn kind(&self) -> IntKind {
+        self.kind
+    }
+
+    /// Get the body, translated into Rust.
+    pub fn body(&self) -> &[MacroToken] {
+        &self.body
+    }
 }
 
 /// A `Var` is our intermediate representation of a variable.
@@ -145,6 +189,36 @@ impl ClangSubItemParser for Var {
                         }
                         MacroParsingBehavior::Default => {}
                     }
+
+                    if cursor.is_macro_function_like() {
+                        handle_function_macro(&cursor, callbacks);
+                    }
+                }
+
+                // We handle function macros ourselves, don't let cexpr try to
+                // parse them as a constant.
+                if cursor.is_macro_function_like() {
+                    if !ctx.options().translate_function_macros {
+                        return Err(ParseError::Continue);
+                    }
+
+                    let (name, function_macro) =
+                        match parse_function_macro(ctx, &cursor) {
+                            Some(m) => m,
+                            None => return Err(ParseError::Continue),
+                        };
+
+                    let ty = Item::builtin_type(
+                        TypeKind::Int(function_macro.kind),
+                        true,
+                        ctx,
+                    );
+                    let val = VarType::FunctionMacro(function_macro);
+
+                    return Ok(ParseResult::New(
+                        Var::new(name, None, ty, Some(val), true),
+                        Some(cursor),
+                    ));
                 }
 
                 let value = parse_macro(ctx, &cursor);
@@ -194,6 +268,10 @@ impl ClangSubItemParser for Var {
                         (TypeKind::Int(IntKind::U8), VarType::Char(c))
                     }
                     EvalResult::Str(val) => {
+                        if let Some(callbacks) = ctx.parse_callbacks() {
+                            callbacks.str_macro(&name, &val);
+                        }
+
                         let char_ty = Item::builtin_type(
                             TypeKind::Int(IntKind::U8),
                             true,
@@ -300,6 +378,198 @@ impl ClangSubItemParser for Var {
     }
 }
 
+/// Let the user know about a function-like macro, splitting its tokens into
+/// the name, the parameters, and the body.
+fn handle_function_macro(
+    cursor: &clang::Cursor,
+    callbacks: &dyn ParseCallbacks,
+) {
+    let tokens = match cursor.tokens() {
+        Some(tokens) => tokens,
+        None => return,
+    };
+
+    let is_closing_paren = |t: &clang::Token| {
+        t.kind == CXToken_Punctuation && t.spelling == ")"
+    };
+    let boundary = match tokens.iter().position(is_closing_paren) {
+        Some(boundary) => boundary,
+        None => return,
+    };
+
+    let name = &tokens[0].spelling;
+    // Skip the name and the opening paren.
+    let params = tokens[2..boundary]
+        .iter()
+        .filter(|t| t.spelling != ",")
+        .map(|t| &*t.spelling)
+        .collect::<Vec<_>>();
+    let body = tokens[boundary + 1..]
+        .iter()
+        .map(|t| &*t.spelling)
+        .collect::<Vec<_>>();
+
+    callbacks.func_macro(name, &params, &body);
+}
+
+/// Try to translate a function-like macro into Rust, which we can do if its
+/// body is integer arithmetic over its parameters and other integer macros.
+fn parse_function_macro(
+    ctx: &mut BindgenContext,
+    cursor: &clang::Cursor,
+) -> Option<(String, FunctionMacro)> {
+    use cexpr::{expr, nom};
+    use cexpr::expr::EvalResult;
+    use cexpr::token::Kind;
+
+    let tokens = cursor.cexpr_tokens()?;
+    let boundary = tokens.iter().position(|t| {
+        t.kind == Kind::Punctuation && &*t.raw == b")"
+    })?;
+
+    let name = String::from_utf8(tokens.get(0)?.raw.to_vec()).ok()?;
+
+    // Skip the name and the opening paren.
+    let mut param_names = vec![];
+    for token in &tokens[2..boundary] {
+        match token.kind {
+            Kind::Identifier => {
+                param_names.push(String::from_utf8(token.raw.to_vec()).ok()?)
+            }
+            Kind::Punctuation if &*token.raw == b"," => {}
+            // Variadic macros can't be translated.
+            _ => return None,
+        }
+    }
+
+    let body = &tokens[boundary + 1..];
+    if body.is_empty() {
+        return None;
+    }
+
+    // Check that cexpr understands the body, with the parameters standing for
+    // arbitrary (non-zero, so that divisions work out) integers.
+    {
+        let mut identifiers = ctx.parsed_macros().clone();
+        for param in &param_names {
+            identifiers
+                .insert(param.clone().into_bytes(), EvalResult::Int(Wrapping(1)));
+        }
+        let parser = expr::IdentifierParser::new(&identifiers);
+        match parser.expr(body) {
+            nom::IResult::Done(rest, EvalResult::Int(..)) if rest.is_empty() => {}
+            _ => return None,
+        }
+    }
+
+    // Translate the body, and infer the kind it's computed in from the
+    // values it uses, the same way we'd type them as constants.
+    let mut translated = vec![];
+    let mut kinds = vec![];
+    // Whether each parameter is only ever used as a shift amount.
+    let mut is_shift_amount = vec![None; param_names.len()];
+    // Whether the previous token ends an operand, which makes a following
+    // `-` a binary one.
+    let mut after_operand = false;
+    // Whether the previous token, ignoring opening parens, is a shift.
+    let mut after_shift = false;
+    for token in body {
+        let raw = str::from_utf8(&token.raw).ok()?;
+        let kind = token.kind;
+        let token = match kind {
+            Kind::Identifier => {
+                if let Some(i) = param_names.iter().position(|p| p == raw) {
+                    let was_shift_amount = is_shift_amount[i].unwrap_or(true);
+                    is_shift_amount[i] = Some(was_shift_amount && after_shift);
+                    MacroToken::Param(i)
+                } else {
+                    // Other macros are inlined, they may not be generated.
+                    match ctx.parsed_macros().get(&*token.raw) {
+                        Some(&EvalResult::Int(Wrapping(value))) => {
+                            kinds.push(default_macro_constant_type(value));
+                            MacroToken::Raw(format!("({})", value))
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            Kind::Literal => {
+                let value = match expr::expr(&[token.clone()]) {
+                    nom::IResult::Done(_, EvalResult::Int(Wrapping(v))) => v,
+                    _ => return None,
+                };
+                kinds.push(default_macro_constant_type(value));
+                MacroToken::Raw(value.to_string())
+            }
+            Kind::Punctuation => {
+                match raw {
+                    "-" => {
+                        // A unary minus needs a signed kind.
+                        if !after_operand {
+                            kinds.push(IntKind::I32);
+                        }
+                        MacroToken::Raw(raw.to_owned())
+                    }
+                    "+" | "*" | "/" | "%" | "<<" | ">>" | "&" | "|" | "^" |
+                    "(" | ")" => MacroToken::Raw(raw.to_owned()),
+                    "~" => MacroToken::Raw("!".to_owned()),
+                    // Comparisons and logical operators result in `bool` in
+                    // Rust, so we leave those alone.
+                    _ => return None,
+                }
+            }
+            Kind::Keyword | Kind::Comment => return None,
+        };
+        translated.push(token);
+
+        after_operand = match kind {
+            Kind::Identifier | Kind::Literal => true,
+            _ => raw == ")",
+        };
+        after_shift = match kind {
+            Kind::Punctuation if raw == "(" => after_shift,
+            _ => raw == "<<" || raw == ">>",
+        };
+    }
+
+    let is_signed = kinds.iter().any(|k| *k == IntKind::I32 || *k == IntKind::I64);
+    let is_wide = kinds.iter().any(|k| *k == IntKind::I64 || *k == IntKind::U64);
+    let kind = match (is_signed, is_wide) {
+        (false, false) => IntKind::U32,
+        (false, true) => IntKind::U64,
+        (true, false) => IntKind::I32,
+        (true, true) => IntKind::I64,
+    };
+
+    // Parameters only used as shift amounts don't need to be of the kind the
+    // body is computed in, like in Rust, so make them `u32`.
+    let params = param_names
+        .into_iter()
+        .zip(is_shift_amount)
+        .map(|(param, is_shift_amount)| {
+            let inferred = match is_shift_amount {
+                Some(true) => IntKind::U32,
+                _ => kind,
+            };
+            let param_kind = ctx.parse_callbacks()
+                .and_then(|c| c.func_macro_param_type(&name, &param))
+                .unwrap_or(inferred);
+            let ty =
+                Item::builtin_type(TypeKind::Int(param_kind), true, ctx);
+            (param, param_kind, ty)
+        })
+        .collect();
+
+    Some((
+        name,
+        FunctionMacro {
+            params,
+            kind,
+            body: translated,
+        },
+    ))
+}
+
 /// Try and parse a macro using all the macros parsed until now.
 fn parse_macro(
     ctx: &BindgenContext,
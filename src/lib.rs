@@ -63,6 +63,7 @@ macro_rules! doc_mod {
 
 mod clang;
 mod codegen;
+mod deps;
 mod features;
 mod ir;
 mod parse;
@@ -78,6 +79,8 @@ doc_mod!(parse, parse_docs);
 doc_mod!(regex_set, regex_set_docs);
 
 pub use features::{LATEST_STABLE_RUST, RUST_TARGET_STRINGS, RustTarget};
+pub use callbacks::CargoCallbacks;
+use deps::DepfileSpec;
 use features::RustFeatures;
 use ir::context::{BindgenContext, ItemId};
 use ir::item::Item;
@@ -86,6 +89,7 @@ use regex_set::RegexSet;
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
 use std::iter;
@@ -168,13 +172,17 @@ impl Default for CodegenConfig {
 ///
 /// 1. [`constified_enum_module()`](#method.constified_enum_module)
 /// 2. [`bitfield_enum()`](#method.bitfield_enum)
-/// 3. [`rustified_enum()`](#method.rustified_enum)
+/// 3. [`newtype_enum()`](#method.newtype_enum)
+/// 4. [`rustified_non_exhaustive_enum()`](#method.rustified_non_exhaustive_enum)
+/// 5. [`rustified_enum()`](#method.rustified_enum)
 ///
 /// For each C enum, bindgen tries to match the pattern in the following order:
 ///
 /// 1. Constified enum module
 /// 2. Bitfield enum
-/// 3. Rustified enum
+/// 3. Newtype enum
+/// 4. Non-exhaustive rustified enum
+/// 5. Rustified enum
 ///
 /// If none of the above patterns match, then bindgen will generate a set of Rust constants.
 #[derive(Debug, Default)]
@@ -210,8 +218,36 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--bitfield-enum".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .newtype_enums
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--newtype-enum".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .rustified_non_exhaustive_enums
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--rustified-non-exhaustive-enum".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -224,8 +260,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--rustified-enum".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -238,8 +274,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--constified-enum-module".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -252,8 +288,50 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--blacklist-type".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .blacklisted_functions
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--blacklist-function".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .blacklisted_vars
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--blacklist-var".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .blacklisted_items
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--blacklist-item".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -263,6 +341,14 @@ impl Builder {
             output_vector.push("--no-layout-tests".into());
         }
 
+        if self.options.compile_time_layout_tests {
+            output_vector.push("--compile-time-layout-tests".into());
+        }
+
+        if !self.options.fail_on_clang_errors {
+            output_vector.push("--no-fail-on-clang-errors".into());
+        }
+
         if self.options.impl_debug {
             output_vector.push("--impl-debug".into());
         }
@@ -271,6 +357,10 @@ impl Builder {
             output_vector.push("--impl-partialeq".into());
         }
 
+        if self.options.impl_try_from {
+            output_vector.push("--impl-try-from".into());
+        }
+
         if !self.options.derive_copy {
             output_vector.push("--no-derive-copy".into());
         }
@@ -330,6 +420,62 @@ impl Builder {
             output_vector.push(prefix.clone());
         }
 
+        if let Some(ref name) = self.options.dynamic_library_name {
+            output_vector.push("--dynamic-loading".into());
+            output_vector.push(name.clone());
+        }
+
+        if self.options.wrap_static_fns {
+            output_vector.push("--wrap-static-fns".into());
+        }
+
+        if self.options.owned_wrappers {
+            output_vector.push("--owned-wrappers".into());
+        }
+
+        if self.options.vtable_generation {
+            output_vector.push("--vtable-generation".into());
+        }
+
+        if self.options.translate_function_macros {
+            output_vector.push("--translate-function-macros".into());
+        }
+
+        if self.options.raw_identifiers {
+            output_vector.push("--raw-identifiers".into());
+        }
+
+        if self.options.stdint_primitives {
+            output_vector.push("--stdint-primitives".into());
+        }
+
+        if self.options.enable_function_attribute_detection {
+            output_vector.push("--enable-function-attribute-detection".into());
+        }
+
+        if let Some(ref spec) = self.options.depfile {
+            // The command line only knows the depfile's target as the output.
+            output_vector.push("--output".into());
+            output_vector.push(spec.output_module.clone());
+            output_vector.push("--depfile".into());
+            output_vector.push(spec.depfile_path.display().to_string());
+        }
+
+        if let Some(ref path) = self.options.wrap_static_fns_path {
+            output_vector.push("--wrap-static-fns-path".into());
+            output_vector.push(path.display().to_string());
+        }
+
+        if let Some(ref suffix) = self.options.wrap_static_fns_suffix {
+            output_vector.push("--wrap-static-fns-suffix".into());
+            output_vector.push(suffix.clone());
+        }
+
+        if let Some(ref path) = self.options.c_layout_assertions {
+            output_vector.push("--c-layout-assertions".into());
+            output_vector.push(path.display().to_string());
+        }
+
         if self.options.emit_ast {
             output_vector.push("--emit-clang-ast".into());
         }
@@ -396,8 +542,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--opaque-type".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -409,8 +555,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--raw-line".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -431,8 +577,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--whitelist-function".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -445,8 +591,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--whitelist-type".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -459,8 +605,36 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--whitelist-var".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .whitelisted_files
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--whitelist-file".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
+                        .into(),
+                );
+            })
+            .count();
+
+        self.options
+            .blacklisted_files
+            .get_items()
+            .iter()
+            .map(|item| {
+                output_vector.push("--blacklist-file".into());
+                output_vector.push(
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -500,8 +674,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--no-partialeq".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -514,8 +688,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--no-copy".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -528,8 +702,8 @@ impl Builder {
             .map(|item| {
                 output_vector.push("--no-hash".into());
                 output_vector.push(
-                    item.trim_left_matches("^")
-                        .trim_right_matches("$")
+                    item.trim_start_matches("^")
+                        .trim_end_matches("$")
                         .into(),
                 );
             })
@@ -583,6 +757,27 @@ impl Builder {
         self
     }
 
+    /// Specify the rust target from the `rust-version` key in the `Cargo.toml`
+    /// of the crate being built.
+    ///
+    /// This is meant to be called from a build script, where Cargo provides the
+    /// `rust-version` in the `CARGO_PKG_RUST_VERSION` environment variable. The
+    /// target is left unchanged if the crate doesn't declare a `rust-version`.
+    pub fn rust_target_from_cargo(self) -> Self {
+        let version = match std::env::var("CARGO_PKG_RUST_VERSION") {
+            Ok(ref version) if !version.is_empty() => version.clone(),
+            _ => return self,
+        };
+
+        match RustTarget::from_rust_version(&version) {
+            Ok(rust_target) => self.rust_target(rust_target),
+            Err(e) => {
+                warn!("Ignoring the crate's rust-version: {}", e);
+                self
+            }
+        }
+    }
+
     /// Disable support for native Rust unions, if supported.
     pub fn disable_untagged_union(mut self) -> Self {
         self.options.rust_features.untagged_union = false;
@@ -674,6 +869,28 @@ impl Builder {
         self
     }
 
+    /// Hide the given function from the generated bindings. Regular expressions
+    /// are supported.
+    pub fn blacklist_function<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.blacklisted_functions.insert(arg);
+        self
+    }
+
+    /// Hide the given variable from the generated bindings. Regular expressions
+    /// are supported.
+    pub fn blacklist_var<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.blacklisted_vars.insert(arg);
+        self
+    }
+
+    /// Hide the given item, regardless of whether it is a type, function,
+    /// variable or module, from the generated bindings. Regular expressions are
+    /// supported.
+    pub fn blacklist_item<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.blacklisted_items.insert(arg);
+        self
+    }
+
     /// Treat the given type as opaque in the generated bindings. Regular
     /// expressions are supported.
     pub fn opaque_type<T: AsRef<str>>(mut self, arg: T) -> Builder {
@@ -729,6 +946,21 @@ impl Builder {
         self.whitelist_var(arg)
     }
 
+    /// Whitelist every item declared in a file whose path matches the given
+    /// regular expression, so that it (and all types that it transitively
+    /// refers to) appears in the generated bindings.
+    pub fn whitelist_file<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.whitelisted_files.insert(arg);
+        self
+    }
+
+    /// Hide every item declared in a file whose path matches the given regular
+    /// expression from the generated bindings.
+    pub fn blacklist_file<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.blacklisted_files.insert(arg);
+        self
+    }
+
 
     /// Mark the given enum (or set of enums, if using a pattern) as being
     /// bitfield-like. Regular expressions are supported.
@@ -740,6 +972,17 @@ impl Builder {
         self
     }
 
+    /// Mark the given enum (or set of enums, if using a pattern) as a newtype.
+    /// Regular expressions are supported.
+    ///
+    /// This makes bindgen generate a type that isn't a Rust `enum`, with an
+    /// associated constant for each variant. Unlike a rustified enum, it is
+    /// sound to receive values that aren't one of the variants from C.
+    pub fn newtype_enum<T: AsRef<str>>(mut self, arg: T) -> Builder {
+        self.options.newtype_enums.insert(arg);
+        self
+    }
+
     /// Mark the given enum (or set of enums, if using a pattern) as a Rust
     /// enum.
     ///
@@ -755,6 +998,21 @@ impl Builder {
         self
     }
 
+    /// Mark the given enum (or set of enums, if using a pattern) as a Rust
+    /// enum with the `#[non_exhaustive]` attribute, if the target Rust version
+    /// supports it. Regular expressions are supported.
+    ///
+    /// The same caveats as for [`rustified_enum`](#method.rustified_enum)
+    /// apply: `#[non_exhaustive]` only affects Rust code matching on the enum,
+    /// and does not make it sound to receive unknown values from C.
+    pub fn rustified_non_exhaustive_enum<T: AsRef<str>>(
+        mut self,
+        arg: T,
+    ) -> Builder {
+        self.options.rustified_non_exhaustive_enums.insert(arg);
+        self
+    }
+
     /// Mark the given enum (or set of enums, if using a pattern) as a set of
     /// constants that should be put into a module.
     ///
@@ -838,6 +1096,29 @@ impl Builder {
         self
     }
 
+    /// Set whether layout tests should be emitted as compile-time assertions
+    /// instead of `#[test]` functions, so that a layout mismatch fails the
+    /// build. Defaults to false.
+    ///
+    /// This requires a Rust target supporting `assert!` in constants. Field
+    /// offsets are still checked by a `#[test]` function unless the target
+    /// also supports `offset_of!`.
+    pub fn compile_time_layout_tests(mut self, doit: bool) -> Self {
+        self.options.compile_time_layout_tests = doit;
+        self
+    }
+
+    /// Set whether generating bindings should fail when clang reports an error
+    /// while parsing the input headers. Defaults to true.
+    ///
+    /// Disabling this produces best-effort bindings for whatever clang managed
+    /// to parse; the errors remain available through
+    /// [`Bindings::diagnostics`](./struct.Bindings.html#method.diagnostics).
+    pub fn fail_on_clang_errors(mut self, doit: bool) -> Self {
+        self.options.fail_on_clang_errors = doit;
+        self
+    }
+
     /// Set whether `Debug` should be implemented, if it can not be derived automatically.
     pub fn impl_debug(mut self, doit: bool) -> Self {
         self.options.impl_debug = doit;
@@ -850,6 +1131,14 @@ impl Builder {
         self
     }
 
+    /// Set whether `TryFrom<repr>` should be implemented for Rust enums, so
+    /// that values received from C can be checked before use. Requires a Rust
+    /// target that supports `TryFrom`.
+    pub fn impl_try_from(mut self, doit: bool) -> Self {
+        self.options.impl_try_from = doit;
+        self
+    }
+
     /// Set whether `Copy` should be derived by default.
     pub fn derive_copy(mut self, doit: bool) -> Self {
         self.options.derive_copy = doit;
@@ -1004,6 +1293,142 @@ impl Builder {
         self
     }
 
+    /// Whether to generate owning wrappers for C++ classes with a destructor.
+    ///
+    /// For a class `Foo`, a `Foo_Owned` wrapper is generated, which derefs to
+    /// `Foo` and calls its destructor when dropped. Its `new` methods call the
+    /// constructors of `Foo`, like `Foo`'s own.
+    pub fn owned_wrappers(mut self, doit: bool) -> Self {
+        self.options.owned_wrappers = doit;
+        self
+    }
+
+    /// Whether to generate a struct of typed function pointers for the
+    /// vtables of C++ classes, rather than an opaque one.
+    ///
+    /// The fields follow the declaration order of the virtual methods, and
+    /// take the object as their first argument. This is only done for classes
+    /// holding their own vtable pointer, without virtual bases, a virtual
+    /// destructor nor template parameters, and whose virtual methods could
+    /// all be parsed, since the vtable layout is not known otherwise.
+    ///
+    /// A class deriving from a single class with a vtable shares its base's
+    /// vtable pointer, so no vtable struct is generated for it: the one
+    /// reachable through its `_base` field only has the base's methods, and
+    /// not the virtual methods the derived class adds after them. Overrides
+    /// can't be told apart from new virtual methods, so the derived layout
+    /// is not known.
+    pub fn vtable_generation(mut self, doit: bool) -> Self {
+        self.options.vtable_generation = doit;
+        self
+    }
+
+    /// Whether to translate function-like macros into Rust.
+    ///
+    /// Macros whose body is integer arithmetic over their parameters and other
+    /// integer macros become a `const fn`, or a `macro_rules!` if the Rust
+    /// target doesn't support `const fn`, exported from the crate. The result
+    /// has the integer type inferred from the literals in the body, like macro
+    /// constants, and so do the parameters, except for those only used as
+    /// shift amounts which are `u32`, unless
+    /// `ParseCallbacks::func_macro_param_type` says otherwise for a parameter.
+    /// They are whitelisted like variables.
+    pub fn translate_function_macros(mut self, doit: bool) -> Self {
+        self.options.translate_function_macros = doit;
+        self
+    }
+
+    /// Whether to emit names colliding with Rust keywords as raw identifiers,
+    /// e.g. `r#type`, instead of appending an underscore.
+    ///
+    /// This only takes effect for Rust targets supporting raw identifiers, and
+    /// never for type names, which other identifiers are derived from.
+    pub fn raw_identifiers(mut self, doit: bool) -> Self {
+        self.options.raw_identifiers = doit;
+        self
+    }
+
+    /// Whether to map well-known `<stdint.h>` and `<stddef.h>` typedefs, like
+    /// `uint32_t`, `size_t` or `intptr_t`, straight to Rust primitives.
+    ///
+    /// The typedefs are resolved while parsing, so neither they nor the
+    /// platform-specific aliases they're defined through (like `__uint32_t`)
+    /// are generated. A typedef is only mapped if its size matches the
+    /// primitive's, using the target's pointer size for `usize` and `isize`.
+    pub fn stdint_primitives(mut self, doit: bool) -> Self {
+        self.options.stdint_primitives = doit;
+        self
+    }
+
+    /// Whether to look at the attributes of functions, to mark those with
+    /// `warn_unused_result` as `#[must_use]`.
+    ///
+    /// This is disabled by default, since looking at the attributes of every
+    /// function slows down parsing.
+    pub fn enable_function_attribute_detection(mut self, doit: bool) -> Self {
+        self.options.enable_function_attribute_detection = doit;
+        self
+    }
+
+    /// Whether to write exported C wrappers for `static` and `inline`
+    /// functions.
+    ///
+    /// Those functions usually have no symbol in the library, so a C file is
+    /// written next to the bindings with a wrapper for each of them, and the
+    /// Rust declarations link against the wrappers instead. The C file includes
+    /// the input headers, and has to be compiled and linked into the final
+    /// binary, with the same include paths used to generate the bindings.
+    ///
+    /// Only free C functions are wrapped, and variadic functions can't be.
+    pub fn wrap_static_fns(mut self, doit: bool) -> Self {
+        self.options.wrap_static_fns = doit;
+        self
+    }
+
+    /// Write a Makefile-style depfile to `depfile`, making `output_module`, the
+    /// file the bindings are written to, depend on every header clang read.
+    pub fn depfile<H: Into<String>, D: Into<PathBuf>>(
+        mut self,
+        output_module: H,
+        depfile: D,
+    ) -> Builder {
+        self.options.depfile = Some(DepfileSpec {
+            output_module: output_module.into(),
+            depfile_path: depfile.into(),
+        });
+        self
+    }
+
+    /// Set the path of the C file written by `wrap_static_fns`.
+    ///
+    /// Defaults to `extern.c` in `OUT_DIR` when run from a build script.
+    /// Otherwise the path is required, and generating the bindings fails
+    /// without it.
+    pub fn wrap_static_fns_path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.options.wrap_static_fns_path = Some(path.into());
+        self
+    }
+
+    /// Set the suffix appended to the name of each function to name its
+    /// wrapper. Defaults to `__extern`.
+    pub fn wrap_static_fns_suffix<T: Into<String>>(mut self, suffix: T) -> Self {
+        self.options.wrap_static_fns_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Write a C file to `path` asserting that the C compiler agrees with the
+    /// size, alignment and field offsets of every generated struct and union.
+    ///
+    /// The file includes the input headers and uses `_Static_assert`, so it
+    /// should be compiled as C11 with the same flags as the library itself,
+    /// e.g. from a build script. Headers given with `header_contents` are
+    /// written inline. Anonymous types aren't checked, and C++ inputs make
+    /// generating the bindings fail.
+    pub fn c_layout_assertions<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.options.c_layout_assertions = Some(path.into());
+        self
+    }
+
     /// Ignore functions.
     pub fn ignore_functions(mut self) -> Builder {
         self.options.codegen_config.functions = false;
@@ -1039,6 +1464,19 @@ impl Builder {
         self
     }
 
+    /// Load the library at runtime instead of linking against it.
+    ///
+    /// Instead of `extern` blocks, a struct with the given name is generated.
+    /// Its `new(path)` constructor opens the library and looks up every
+    /// function; each function is stored as a `Result`, so a missing symbol is
+    /// an error rather than a link failure. The generated code depends on the
+    /// [`libloading`](https://crates.io/crates/libloading) crate, version 0.6
+    /// or later.
+    pub fn dynamic_library_name<T: Into<String>>(mut self, name: T) -> Builder {
+        self.options.dynamic_library_name = Some(name.into());
+        self
+    }
+
     /// Allows configuring types in different situations, see the
     /// [`ParseCallbacks`](./callbacks/trait.ParseCallbacks.html) documentation.
     pub fn parse_callbacks(
@@ -1083,7 +1521,7 @@ impl Builder {
     }
 
     /// Generate the Rust bindings using the options built up thus far.
-    pub fn generate(mut self) -> Result<Bindings, ()> {
+    pub fn generate(mut self) -> Result<Bindings, BindgenError> {
         self.options.input_header = self.input_headers.pop();
         self.options.clang_args.extend(
             self.input_headers
@@ -1108,12 +1546,6 @@ impl Builder {
     /// issues. The resulting file will be named something like `__bindgen.i` or
     /// `__bindgen.ii`
     pub fn dump_preprocessed_input(&self) -> io::Result<()> {
-        fn check_is_cpp(name_file: &str) -> bool {
-            name_file.ends_with(".hpp") || name_file.ends_with(".hxx")
-                || name_file.ends_with(".hh")
-                || name_file.ends_with(".h++")
-        }
-        
         let clang = clang_sys::support::Clang::find(None, &[]).ok_or_else(|| {
             io::Error::new(io::ErrorKind::Other, "Cannot find clang executable")
         })?;
@@ -1123,9 +1555,7 @@ impl Builder {
         let mut wrapper_contents = String::new();
 
         // Whether we are working with C or C++ inputs.
-        let mut is_cpp = self.options.clang_args.windows(2).any(|w| {
-            w[0] == "-x=c++" || w[1] == "-x=c++" || w == &["-x", "c++"]
-        });
+        let mut is_cpp = args_are_cpp(&self.options.clang_args);
 
         // For each input header, add `#include "$header"`.
         for header in &self.input_headers {
@@ -1219,6 +1649,18 @@ struct BindgenOptions {
     /// anywhere in the generated code.
     blacklisted_types: RegexSet,
 
+    /// The set of functions that have been blacklisted and should not appear
+    /// in the generated code.
+    blacklisted_functions: RegexSet,
+
+    /// The set of variables that have been blacklisted and should not appear
+    /// in the generated code.
+    blacklisted_vars: RegexSet,
+
+    /// The set of items, regardless of item-type, that have been blacklisted
+    /// and should not appear in the generated code.
+    blacklisted_items: RegexSet,
+
     /// The set of types that should be treated as opaque structures in the
     /// generated code.
     opaque_types: RegexSet,
@@ -1240,12 +1682,26 @@ struct BindgenOptions {
     /// Whitelisted variables. See docs for `whitelisted_types` for more.
     whitelisted_vars: RegexSet,
 
+    /// Whitelisted files. Every item declared in a file whose path matches is
+    /// whitelisted. See docs for `whitelisted_types` for more.
+    whitelisted_files: RegexSet,
+
+    /// The set of files whose items have been blacklisted and should not
+    /// appear in the generated code.
+    blacklisted_files: RegexSet,
+
     /// The enum patterns to mark an enum as bitfield.
     bitfield_enums: RegexSet,
 
+    /// The enum patterns to mark an enum as a newtype.
+    newtype_enums: RegexSet,
+
     /// The enum patterns to mark an enum as a Rust enum.
     rustified_enums: RegexSet,
 
+    /// The enum patterns to mark an enum as a non-exhaustive Rust enum.
+    rustified_non_exhaustive_enums: RegexSet,
+
     /// The enum patterns to mark an enum as a module of constants.
     constified_enum_modules: RegexSet,
 
@@ -1271,6 +1727,14 @@ struct BindgenOptions {
     /// True if we should generate layout tests for generated structures.
     layout_tests: bool,
 
+    /// True if layout tests should be compile-time assertions rather than
+    /// `#[test]` functions.
+    compile_time_layout_tests: bool,
+
+    /// True if we should fail to generate bindings when clang reports an
+    /// error while parsing the input.
+    fail_on_clang_errors: bool,
+
     /// True if we should implement the Debug trait for C/C++ structures and types
     /// that do not support automatically deriving Debug.
     impl_debug: bool,
@@ -1279,6 +1743,10 @@ struct BindgenOptions {
     /// that do not support autoamically deriving PartialEq.
     impl_partialeq: bool,
 
+    /// True if we should implement `TryFrom` from the underlying integer type
+    /// for Rust enums.
+    impl_try_from: bool,
+
     /// True if we should derive Copy trait implementations for C/C++ structures
     /// and types.
     derive_copy: bool,
@@ -1317,6 +1785,10 @@ struct BindgenOptions {
     /// An optional prefix for the "raw" types, like `c_int`, `c_void`...
     ctypes_prefix: Option<String>,
 
+    /// The name of the struct to generate for a library loaded at runtime, if
+    /// functions shouldn't be linked against.
+    dynamic_library_name: Option<String>,
+
     /// Whether to time the bindgen phases.
     time_phases: bool,
 
@@ -1368,6 +1840,45 @@ struct BindgenOptions {
     /// Whether to generate inline functions. Defaults to false.
     generate_inline_functions: bool,
 
+    /// Whether to generate owning wrappers for classes with a destructor.
+    /// Defaults to false.
+    owned_wrappers: bool,
+
+    /// Whether to generate typed vtable structs. Defaults to false.
+    vtable_generation: bool,
+
+    /// Whether to translate function-like macros into Rust. Defaults to false.
+    translate_function_macros: bool,
+
+    /// Whether to use raw identifiers for names colliding with Rust keywords.
+    /// Defaults to false.
+    raw_identifiers: bool,
+
+    /// Whether to map well-known fixed-width and pointer-sized typedefs
+    /// straight to Rust primitives. Defaults to false.
+    stdint_primitives: bool,
+
+    /// Whether to detect function attributes, like `warn_unused_result`.
+    /// Defaults to false.
+    enable_function_attribute_detection: bool,
+
+    /// Whether to write exported wrappers for static and inline functions.
+    /// Defaults to false.
+    wrap_static_fns: bool,
+
+    /// The path of the C file with the static function wrappers.
+    wrap_static_fns_path: Option<PathBuf>,
+
+    /// The suffix of the static function wrappers' names.
+    wrap_static_fns_suffix: Option<String>,
+
+    /// Where to write the C file with layout assertions, if anywhere.
+    c_layout_assertions: Option<PathBuf>,
+
+    /// Where to write the depfile listing the headers the bindings depend on,
+    /// if anywhere.
+    depfile: Option<DepfileSpec>,
+
     /// Wether to whitelist types recursively. Defaults to true.
     whitelist_recursively: bool,
 
@@ -1417,18 +1928,54 @@ struct BindgenOptions {
 impl ::std::panic::UnwindSafe for BindgenOptions {}
 
 impl BindgenOptions {
-    fn build(&mut self) {
-        self.whitelisted_vars.build();
-        self.whitelisted_types.build();
-        self.whitelisted_functions.build();
-        self.blacklisted_types.build();
-        self.opaque_types.build();
-        self.bitfield_enums.build();
-        self.constified_enum_modules.build();
-        self.rustified_enums.build();
-        self.no_partialeq_types.build();
-        self.no_copy_types.build();
-        self.no_hash_types.build();
+    fn build(&mut self) -> Result<(), BindgenError> {
+        let mut regex_sets = [
+            &mut self.whitelisted_vars,
+            &mut self.whitelisted_files,
+            &mut self.blacklisted_files,
+            &mut self.whitelisted_types,
+            &mut self.whitelisted_functions,
+            &mut self.blacklisted_types,
+            &mut self.blacklisted_functions,
+            &mut self.blacklisted_vars,
+            &mut self.blacklisted_items,
+            &mut self.opaque_types,
+            &mut self.bitfield_enums,
+            &mut self.newtype_enums,
+            &mut self.constified_enum_modules,
+            &mut self.rustified_enums,
+            &mut self.rustified_non_exhaustive_enums,
+            &mut self.no_partialeq_types,
+            &mut self.no_copy_types,
+            &mut self.no_hash_types,
+        ];
+        for regex_set in regex_sets.iter_mut() {
+            regex_set
+                .build()
+                .map_err(|e| BindgenError::InvalidRegex(e.to_string()))?;
+        }
+
+        if self.wrap_static_fns && self.wrap_static_fns_path.is_none() {
+            let out_dir = std::env::var_os("OUT_DIR")
+                .ok_or(BindgenError::NoStaticWrappersPath)?;
+            self.wrap_static_fns_path =
+                Some(PathBuf::from(out_dir).join("extern.c"));
+        }
+
+        if let Some(ref path) = self.c_layout_assertions {
+            let is_cpp = args_are_cpp(&self.clang_args) ||
+                self.input_headers().into_iter().any(check_is_cpp) ||
+                self.input_unsaved_files
+                    .iter()
+                    .any(|f| check_is_cpp(&f.name.to_string_lossy()));
+            if is_cpp {
+                return Err(BindgenError::CLayoutAssertions(
+                    path.clone(),
+                    "C++ inputs are not supported".into(),
+                ));
+            }
+        }
+        Ok(())
     }
 
     /// Update rust target version
@@ -1443,6 +1990,29 @@ impl BindgenOptions {
     pub fn rust_features(&self) -> RustFeatures {
         self.rust_features
     }
+
+    /// Get the suffix of the static function wrappers' names.
+    pub fn wrap_static_fns_suffix(&self) -> &str {
+        self.wrap_static_fns_suffix.as_ref().map_or("__extern", |s| &**s)
+    }
+
+    /// Get the input headers, in the order clang reads them: every header
+    /// passed with `-include`, then the main one.
+    fn input_headers(&self) -> Vec<&str> {
+        let mut headers = vec![];
+        let mut args = self.clang_args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "-include" {
+                if let Some(header) = args.next() {
+                    headers.push(&**header);
+                }
+            }
+        }
+        if let Some(ref header) = self.input_header {
+            headers.push(&**header);
+        }
+        headers
+    }
 }
 
 impl Default for BindgenOptions {
@@ -1453,21 +2023,31 @@ impl Default for BindgenOptions {
             rust_target: rust_target,
             rust_features: rust_target.into(),
             blacklisted_types: Default::default(),
+            blacklisted_functions: Default::default(),
+            blacklisted_vars: Default::default(),
+            blacklisted_items: Default::default(),
             opaque_types: Default::default(),
             rustfmt_path: Default::default(),
             whitelisted_types: Default::default(),
             whitelisted_functions: Default::default(),
             whitelisted_vars: Default::default(),
+            whitelisted_files: Default::default(),
+            blacklisted_files: Default::default(),
             bitfield_enums: Default::default(),
+            newtype_enums: Default::default(),
             rustified_enums: Default::default(),
+            rustified_non_exhaustive_enums: Default::default(),
             constified_enum_modules: Default::default(),
             builtins: false,
             emit_ast: false,
             emit_ir: false,
             emit_ir_graphviz: None,
             layout_tests: true,
+            compile_time_layout_tests: false,
+            fail_on_clang_errors: true,
             impl_debug: false,
             impl_partialeq: false,
+            impl_try_from: false,
             derive_copy: true,
             derive_debug: true,
             derive_default: false,
@@ -1480,6 +2060,7 @@ impl Default for BindgenOptions {
             disable_name_namespacing: false,
             use_core: false,
             ctypes_prefix: None,
+            dynamic_library_name: None,
             namespaced_constants: true,
             msvc_mangling: false,
             convert_floats: true,
@@ -1493,6 +2074,17 @@ impl Default for BindgenOptions {
             conservative_inline_namespaces: false,
             generate_comments: true,
             generate_inline_functions: false,
+            owned_wrappers: false,
+            vtable_generation: false,
+            translate_function_macros: false,
+            raw_identifiers: false,
+            stdint_primitives: false,
+            enable_function_attribute_detection: false,
+            wrap_static_fns: false,
+            wrap_static_fns_path: None,
+            wrap_static_fns_suffix: None,
+            c_layout_assertions: None,
+            depfile: None,
             whitelist_recursively: true,
             objc_extern_crate: false,
             enable_mangling: true,
@@ -1507,6 +2099,87 @@ impl Default for BindgenOptions {
     }
 }
 
+/// Write the C file defining the exported wrappers of static and inline
+/// functions.
+///
+/// Headers given with `header_contents` don't exist on disk, so their contents
+/// are written inline instead of included.
+fn write_static_wrappers(
+    options: &BindgenOptions,
+    wrappers: &[String],
+    path: &Path,
+) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut file = File::create(path)?;
+
+    // Include the same headers clang parsed, in the same order.
+    for header in options.input_headers() {
+        writeln!(file, "#include \"{}\"", header)?;
+    }
+    for unsaved in &options.input_unsaved_files {
+        writeln!(file, "#line 1 \"{}\"", unsaved.name.to_string_lossy())?;
+        writeln!(file, "{}", unsaved.contents.to_string_lossy())?;
+    }
+
+    writeln!(file, "")?;
+    writeln!(file, "// Static wrappers")?;
+    writeln!(file, "")?;
+    for wrapper in wrappers {
+        writeln!(file, "{}", wrapper)?;
+    }
+
+    Ok(())
+}
+
+/// Whether the given clang arguments ask for C++.
+fn args_are_cpp(clang_args: &[String]) -> bool {
+    clang_args.windows(2).any(|w| {
+        w[0] == "-x=c++" || w[1] == "-x=c++" || w == &["-x", "c++"]
+    })
+}
+
+/// Whether the given header name has a C++ extension.
+fn check_is_cpp(name_file: &str) -> bool {
+    name_file.ends_with(".hpp") || name_file.ends_with(".hxx")
+        || name_file.ends_with(".hh")
+        || name_file.ends_with(".h++")
+}
+
+/// Write the C file asserting the layouts of the generated types.
+///
+/// Headers given with `header_contents` don't exist on disk, so their contents
+/// are written inline instead of included.
+fn write_c_layout_assertions(
+    options: &BindgenOptions,
+    assertions: &[String],
+    path: &Path,
+) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut file = File::create(path)?;
+
+    writeln!(file, "#include <stddef.h>")?;
+    for header in options.input_headers() {
+        writeln!(file, "#include \"{}\"", header)?;
+    }
+    for unsaved in &options.input_unsaved_files {
+        writeln!(file, "#line 1 \"{}\"", unsaved.name.to_string_lossy())?;
+        writeln!(file, "{}", unsaved.contents.to_string_lossy())?;
+    }
+
+    for assertion in assertions {
+        writeln!(file, "")?;
+        writeln!(file, "{}", assertion)?;
+    }
+
+    Ok(())
+}
+
 fn ensure_libclang_is_loaded() {
     if clang_sys::is_loaded() {
         return;
@@ -1528,21 +2201,209 @@ fn ensure_libclang_is_loaded() {
     clang_sys::set_library(Some(LIBCLANG.clone()));
 }
 
+/// Error type for rust-bindgen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindgenError {
+    /// The header was a folder.
+    FolderAsHeader(PathBuf),
+    /// Permissions to read the header were insufficient.
+    InsufficientPermissions(PathBuf),
+    /// The header does not exist.
+    NotExist(PathBuf),
+    /// Clang reported errors while parsing the input. Contains every
+    /// diagnostic clang emitted, not only the errors.
+    ClangDiagnostics(Vec<Diagnostic>),
+    /// One of the regular expressions given to the builder failed to compile.
+    InvalidRegex(String),
+    /// Static function wrappers were requested without a path to write them
+    /// to, outside of a build script.
+    NoStaticWrappersPath,
+    /// The C file with the static function wrappers could not be written.
+    StaticWrappers(PathBuf, String),
+    /// The depfile could not be written.
+    Depfile(PathBuf, String),
+    /// The C file with the layout assertions could not be written.
+    CLayoutAssertions(PathBuf, String),
+    /// Something went wrong inside bindgen, for example libclang could not
+    /// produce a translation unit at all.
+    Internal(String),
+}
+
+impl fmt::Display for BindgenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BindgenError::FolderAsHeader(ref h) => {
+                write!(f, "'{}' is a folder", h.display())
+            }
+            BindgenError::InsufficientPermissions(ref h) => {
+                write!(f, "insufficient permissions to read '{}'", h.display())
+            }
+            BindgenError::NotExist(ref h) => {
+                write!(f, "header '{}' does not exist.", h.display())
+            }
+            BindgenError::ClangDiagnostics(ref diagnostics) => {
+                write!(f, "clang diagnosed error:")?;
+                for diagnostic in diagnostics {
+                    write!(f, "\n{}", diagnostic)?;
+                }
+                Ok(())
+            }
+            BindgenError::InvalidRegex(ref e) => {
+                write!(f, "invalid regex: {}", e)
+            }
+            BindgenError::NoStaticWrappersPath => {
+                write!(
+                    f,
+                    "static function wrappers need a path outside of a build \
+                     script"
+                )
+            }
+            BindgenError::StaticWrappers(ref path, ref e) => {
+                write!(
+                    f,
+                    "could not write static function wrappers to '{}': {}",
+                    path.display(),
+                    e
+                )
+            }
+            BindgenError::Depfile(ref path, ref e) => {
+                write!(f, "could not write depfile '{}': {}", path.display(), e)
+            }
+            BindgenError::CLayoutAssertions(ref path, ref e) => {
+                write!(
+                    f,
+                    "could not write layout assertions to '{}': {}",
+                    path.display(),
+                    e
+                )
+            }
+            BindgenError::Internal(ref e) => {
+                write!(f, "internal error: {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BindgenError {
+    fn description(&self) -> &str {
+        match *self {
+            BindgenError::FolderAsHeader(..) => "header is a folder",
+            BindgenError::InsufficientPermissions(..) => {
+                "insufficient permissions to read header"
+            }
+            BindgenError::NotExist(..) => "header does not exist",
+            BindgenError::ClangDiagnostics(..) => "clang diagnosed error",
+            BindgenError::InvalidRegex(..) => "invalid regex",
+            BindgenError::NoStaticWrappersPath => {
+                "no path for static function wrappers"
+            }
+            BindgenError::StaticWrappers(..) => {
+                "could not write static function wrappers"
+            }
+            BindgenError::Depfile(..) => "could not write depfile",
+            BindgenError::CLayoutAssertions(..) => {
+                "could not write layout assertions"
+            }
+            BindgenError::Internal(..) => "internal error",
+        }
+    }
+}
+
+/// How severe a `Diagnostic` reported by clang is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiagnosticSeverity {
+    /// The diagnostic has been suppressed, e.g. by a command-line option.
+    Ignored,
+    /// Supplementary information attached to a previous diagnostic.
+    Note,
+    /// A suspicious construct that doesn't prevent parsing.
+    Warning,
+    /// An ill-formed construct. The bindings may be incomplete.
+    Error,
+    /// An error clang could not recover from.
+    Fatal,
+}
+
+impl fmt::Display for DiagnosticSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            DiagnosticSeverity::Ignored => "ignored",
+            DiagnosticSeverity::Note => "note",
+            DiagnosticSeverity::Warning => "warning",
+            DiagnosticSeverity::Error => "error",
+            DiagnosticSeverity::Fatal => "fatal error",
+        };
+        s.fmt(f)
+    }
+}
+
+/// A diagnostic clang reported while parsing the input headers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    /// How severe this diagnostic is.
+    pub severity: DiagnosticSeverity,
+    /// The file the diagnostic points into, if any.
+    pub file: Option<PathBuf>,
+    /// The line the diagnostic points to, starting at 1, or 0 if unknown.
+    pub line: usize,
+    /// The column the diagnostic points to, starting at 1, or 0 if unknown.
+    pub column: usize,
+    /// The diagnostic text, without location or severity information.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn from_clang(diag: &clang::Diagnostic) -> Self {
+        use clang_sys::*;
+
+        let severity = match diag.severity() {
+            CXDiagnostic_Ignored => DiagnosticSeverity::Ignored,
+            CXDiagnostic_Note => DiagnosticSeverity::Note,
+            CXDiagnostic_Warning => DiagnosticSeverity::Warning,
+            CXDiagnostic_Error => DiagnosticSeverity::Error,
+            _ => DiagnosticSeverity::Fatal,
+        };
+        let (file, line, column, _) = diag.location().location();
+        Diagnostic {
+            severity: severity,
+            file: file.name().map(PathBuf::from),
+            line: line,
+            column: column,
+            message: diag.spelling(),
+        }
+    }
+
+    /// Is this diagnostic an error (or a fatal error)?
+    pub fn is_error(&self) -> bool {
+        self.severity >= DiagnosticSeverity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref file) = self.file {
+            write!(f, "{}:{}:{}: ", file.display(), self.line, self.column)?;
+        }
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
 /// Generated Rust bindings.
 #[derive(Debug)]
 pub struct Bindings {
     options: BindgenOptions,
     module: quote::Tokens,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl Bindings {
     /// Generate bindings for the given options.
     pub(crate) fn generate(
         mut options: BindgenOptions,
-    ) -> Result<Bindings, ()> {
+    ) -> Result<Bindings, BindgenError> {
         ensure_libclang_is_loaded();
 
-        options.build();
+        options.build()?;
 
         // Filter out include paths and similar stuff, so we don't incorrectly
         // promote them to `-isystem`.
@@ -1612,17 +2473,14 @@ impl Bindings {
         if let Some(h) = options.input_header.as_ref() {
             if let Ok(md) = std::fs::metadata(h) {
                 if md.is_dir() {
-                    eprintln!("error: '{}' is a folder", h);
-                    return Err(());
+                    return Err(BindgenError::FolderAsHeader(h.into()));
                 }
                 if !can_read(&md.permissions()) {
-                    eprintln!("error: insufficient permissions to read '{}'", h);
-                    return Err(());
+                    return Err(BindgenError::InsufficientPermissions(h.into()));
                 }
                 options.clang_args.push(h.clone())
             } else {
-                eprintln!("error: header '{}' does not exist.", h);
-                return Err(());
+                return Err(BindgenError::NotExist(h.into()));
             }
         }
 
@@ -1631,24 +2489,53 @@ impl Bindings {
         }
 
         let time_phases = options.time_phases;
-        let mut context = BindgenContext::new(options);
+        let mut context = BindgenContext::new(options)?;
 
-        {
+        let diagnostics = {
             let _t = time::Timer::new("parse")
                                   .with_output(time_phases);
-            parse(&mut context)?;
+            parse(&mut context)?
+        };
+
+        if let Some(spec) = context.options().depfile.as_ref() {
+            spec.write(context.deps()).map_err(|e| {
+                BindgenError::Depfile(spec.depfile_path.clone(), e.to_string())
+            })?;
         }
 
-        let (items, options) = codegen::codegen(context);
+        let (items, static_wrappers, c_layout_assertions, options) =
+            codegen::codegen(context);
+
+        if let Some(ref path) = options.wrap_static_fns_path {
+            if options.wrap_static_fns && !static_wrappers.is_empty() {
+                write_static_wrappers(&options, &static_wrappers, path)
+                    .map_err(|e| {
+                        BindgenError::StaticWrappers(path.clone(), e.to_string())
+                    })?;
+            }
+        }
+
+        if let Some(ref path) = options.c_layout_assertions {
+            write_c_layout_assertions(&options, &c_layout_assertions, path)
+                .map_err(|e| {
+                    BindgenError::CLayoutAssertions(path.clone(), e.to_string())
+                })?;
+        }
 
         Ok(Bindings {
             options: options,
             module: quote! {
                 #( #items )*
-            }
+            },
+            diagnostics: diagnostics,
         })
     }
 
+    /// The diagnostics clang reported while parsing the input headers.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
     /// Convert these bindings into source text (with raw lines prepended).
     pub fn to_string(&self) -> String {
         let mut bytes = vec![];
@@ -1808,20 +2695,42 @@ fn parse_one(
     CXChildVisit_Continue
 }
 
-/// Parse the Clang AST into our `Item` internal representation.
-fn parse(context: &mut BindgenContext) -> Result<(), ()> {
+/// Parse the Clang AST into our `Item` internal representation, returning the
+/// diagnostics clang reported along the way.
+fn parse(context: &mut BindgenContext) -> Result<Vec<Diagnostic>, BindgenError> {
     use clang_sys::*;
 
-    let mut any_error = false;
-    for d in context.translation_unit().diags().iter() {
-        let msg = d.format();
-        let is_err = d.severity() >= CXDiagnostic_Error;
-        eprintln!("{}, err: {}", msg, is_err);
-        any_error |= is_err;
+    let diagnostics = context
+        .translation_unit()
+        .diags()
+        .iter()
+        .map(Diagnostic::from_clang)
+        .collect::<Vec<_>>();
+
+    let fail = context.options().fail_on_clang_errors &&
+        diagnostics.iter().any(Diagnostic::is_error);
+
+    match context.parse_callbacks() {
+        Some(cb) => {
+            for diagnostic in &diagnostics {
+                cb.diagnostic(diagnostic);
+            }
+        }
+        None => {
+            // Nobody is listening, so let the user know about the diagnostics
+            // that aren't part of the error we return.
+            for diagnostic in &diagnostics {
+                if diagnostic.severity > DiagnosticSeverity::Ignored &&
+                    !(fail && diagnostic.is_error())
+                {
+                    eprintln!("{}", diagnostic);
+                }
+            }
+        }
     }
 
-    if any_error {
-        return Err(());
+    if fail {
+        return Err(BindgenError::ClangDiagnostics(diagnostics));
     }
 
     let cursor = context.translation_unit().cursor();
@@ -1838,6 +2747,18 @@ fn parse(context: &mut BindgenContext) -> Result<(), ()> {
         cursor.visit(|cur| dump_if_not_builtin(&cur));
     }
 
+    // The input headers aren't reached through an `#include`, so report them
+    // up front.
+    let input_headers = context
+        .options()
+        .input_headers()
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    for header in &input_headers {
+        context.include_file(header);
+    }
+
     let root = context.root_module();
     context.with_module(root, |context| {
         cursor.visit(|cursor| parse_one(context, cursor, None))
@@ -1847,7 +2768,7 @@ fn parse(context: &mut BindgenContext) -> Result<(), ()> {
         context.current_module() == context.root_module(),
         "How did this happen?"
     );
-    Ok(())
+    Ok(diagnostics)
 }
 
 /// Extracted Clang version data
@@ -1894,6 +2815,27 @@ pub fn clang_version() -> ClangVersion {
     }
 }
 
+#[test]
+fn diagnostic_display() {
+    let mut diagnostic = Diagnostic {
+        severity: DiagnosticSeverity::Warning,
+        file: Some(PathBuf::from("foo.h")),
+        line: 3,
+        column: 2,
+        message: "careful".into(),
+    };
+    assert_eq!(diagnostic.to_string(), "foo.h:3:2: warning: careful");
+    assert!(!diagnostic.is_error());
+
+    diagnostic.severity = DiagnosticSeverity::Fatal;
+    diagnostic.file = None;
+    assert_eq!(diagnostic.to_string(), "fatal error: careful");
+    assert!(diagnostic.is_error());
+
+    assert!(DiagnosticSeverity::Note < DiagnosticSeverity::Warning);
+    assert!(DiagnosticSeverity::Warning < DiagnosticSeverity::Error);
+}
+
 /// Test command_line_flag function.
 #[test]
 fn commandline_flag_unit_test_function() {
@@ -1940,4 +2882,22 @@ fn commandline_flag_unit_test_function() {
         |ref x| command_line_flags.contains(x),
     ));
 
+    //Test 3
+    let bindings = ::builder()
+        .header("input_header")
+        .depfile("bindings.rs", "bindings.d");
+
+    let command_line_flags = bindings.command_line_flags();
+    let test_cases = vec![
+        "--output",
+        "bindings.rs",
+        "--depfile",
+        "bindings.d",
+    ].iter()
+        .map(|&x| x.into())
+        .collect::<Vec<String>>();
+
+    assert!(test_cases.iter().all(
+        |ref x| command_line_flags.contains(x),
+    ));
 }
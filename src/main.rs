@@ -46,18 +46,22 @@ pub fn main() {
     match builder_from_flags(bind_args.into_iter()) {
         Ok((builder, output, verbose)) => {
 
-            let builder_result = panic::catch_unwind(|| {
-                builder.generate().expect("Unable to generate bindings")
-            });
-
-            if builder_result.is_err() {
-                if verbose {
-                    print_verbose_err();
+            // Errors we know about are reported through `BindgenError`, so
+            // unwinding here only catches genuine bindgen bugs.
+            let bindings = match panic::catch_unwind(|| builder.generate()) {
+                Ok(Ok(bindings)) => bindings,
+                Ok(Err(error)) => {
+                    eprintln!("error: {}", error);
+                    std::process::exit(1);
+                }
+                Err(_) => {
+                    if verbose {
+                        print_verbose_err();
+                    }
+                    std::process::exit(1);
                 }
-                std::process::exit(1);
-            }
+            };
 
-            let bindings = builder_result.unwrap();
             bindings.write(output).expect("Unable to write output");
         }
         Err(error) => {
@@ -34,6 +34,21 @@ where
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("newtype-enum")
+                .long("newtype-enum")
+                .help("Mark any enum whose name matches <regex> as a newtype.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("rustified-non-exhaustive-enum")
+                .long("rustified-non-exhaustive-enum")
+                .help("Mark any enum whose name matches <regex> as a \
+                       non-exhaustive Rust enum instead of a set of constants.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("rustified-enum")
                 .long("rustified-enum")
                 .help("Mark any enum whose name matches <regex> as a Rust enum \
@@ -57,9 +72,38 @@ where
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("blacklist-function")
+                .long("blacklist-function")
+                .help("Mark <function> as hidden.")
+                .value_name("function")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("blacklist-var")
+                .long("blacklist-var")
+                .help("Mark <var> as hidden.")
+                .value_name("var")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("blacklist-item")
+                .long("blacklist-item")
+                .help("Mark <item> as hidden.")
+                .value_name("item")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("no-layout-tests")
                 .long("no-layout-tests")
                 .help("Avoid generating layout tests for any type."),
+            Arg::with_name("compile-time-layout-tests")
+                .long("compile-time-layout-tests")
+                .help("Generate layout tests as compile-time assertions \
+                       instead of #[test] functions."),
+            Arg::with_name("no-fail-on-clang-errors")
+                .long("no-fail-on-clang-errors")
+                .help("Generate bindings even if clang reports errors while \
+                       parsing the input."),
             Arg::with_name("no-derive-copy")
                 .long("no-derive-copy")
                 .help("Avoid deriving Copy on any type."),
@@ -78,6 +122,10 @@ where
                 .long("impl-partialeq")
                 .help("Create PartialEq implementation, if it can not be derived \
                        automatically."),
+            Arg::with_name("impl-try-from")
+                .long("impl-try-from")
+                .help("Create TryFrom implementations from the underlying \
+                       integer type for Rust enums."),
             Arg::with_name("with-derive-default")
                 .long("with-derive-default")
                 .help("Derive Default on any type."),
@@ -124,6 +172,12 @@ where
                       ::std::os::raw.")
                 .value_name("prefix")
                 .takes_value(true),
+            Arg::with_name("dynamic-loading")
+                .long("dynamic-loading")
+                .help("Generate a struct named <name> that loads the library \
+                       at runtime instead of linking against it.")
+                .value_name("name")
+                .takes_value(true),
             Arg::with_name("time-phases")
                 .long("time-phases")
                 .help("Time the different bindgen phases and print to stderr"),
@@ -216,6 +270,61 @@ where
             Arg::with_name("generate-inline-functions")
                 .long("generate-inline-functions")
                 .help("Generate inline functions."),
+            Arg::with_name("owned-wrappers")
+                .long("owned-wrappers")
+                .help("Generate wrappers for C++ classes with a destructor, \
+                       which run it when dropped."),
+            Arg::with_name("vtable-generation")
+                .long("vtable-generation")
+                .help("Generate typed vtable structs for C++ classes \
+                       holding their own vtable pointer. Classes sharing \
+                       the vtable of their base get none."),
+            Arg::with_name("translate-function-macros")
+                .long("translate-function-macros")
+                .help("Translate function-like macros doing integer \
+                       arithmetic into const fns."),
+            Arg::with_name("raw-identifiers")
+                .long("raw-identifiers")
+                .help("Use raw identifiers for names colliding with Rust \
+                       keywords, instead of appending an underscore."),
+            Arg::with_name("stdint-primitives")
+                .long("stdint-primitives")
+                .help("Map typedefs like uint32_t and size_t straight to \
+                       Rust primitives, without generating their aliases."),
+            Arg::with_name("enable-function-attribute-detection")
+                .long("enable-function-attribute-detection")
+                .help("Mark functions with warn_unused_result as \
+                       #[must_use]. Slows down parsing."),
+            Arg::with_name("depfile")
+                .long("depfile")
+                .help("Write a Makefile-style depfile listing the headers the \
+                       bindings depend on to <path>. Requires --output.")
+                .value_name("path")
+                .takes_value(true)
+                .requires("output"),
+            Arg::with_name("wrap-static-fns")
+                .long("wrap-static-fns")
+                .help("Write a C file with exported wrappers for static and \
+                       inline functions, and bind to the wrappers."),
+            Arg::with_name("wrap-static-fns-path")
+                .long("wrap-static-fns-path")
+                .help("The path of the C file with the static function \
+                       wrappers. Defaults to the --output path with a .c \
+                       extension.")
+                .value_name("path")
+                .takes_value(true),
+            Arg::with_name("wrap-static-fns-suffix")
+                .long("wrap-static-fns-suffix")
+                .help("The suffix appended to the names of the static \
+                       function wrappers. Defaults to __extern.")
+                .value_name("suffix")
+                .takes_value(true),
+            Arg::with_name("c-layout-assertions")
+                .long("c-layout-assertions")
+                .help("Write a C file to <path> with _Static_asserts checking \
+                       the layout of every generated struct and union.")
+                .value_name("path")
+                .takes_value(true),
             Arg::with_name("whitelist-type")
                 .long("whitelist-type")
                 .help("Only generate types matching <regex>. Other non-whitelisted types will \
@@ -233,6 +342,22 @@ where
                 .takes_value(true)
                 .multiple(true)
                 .number_of_values(1),
+            Arg::with_name("whitelist-file")
+                .long("whitelist-file")
+                .help("Whitelist all the items declared in a file whose path \
+                       matches <regex>.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+            Arg::with_name("blacklist-file")
+                .long("blacklist-file")
+                .help("Mark all the items declared in a file whose path \
+                       matches <regex> as hidden.")
+                .value_name("regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
             Arg::with_name("verbose")
                 .long("verbose")
                 .help("Print verbose error messages."),
@@ -309,6 +434,18 @@ where
         }
     }
 
+    if let Some(newtypes) = matches.values_of("newtype-enum") {
+        for regex in newtypes {
+            builder = builder.newtype_enum(regex);
+        }
+    }
+
+    if let Some(rustifieds) = matches.values_of("rustified-non-exhaustive-enum") {
+        for regex in rustifieds {
+            builder = builder.rustified_non_exhaustive_enum(regex);
+        }
+    }
+
     if let Some(rustifieds) = matches.values_of("rustified-enum") {
         for regex in rustifieds {
             builder = builder.rustified_enum(regex);
@@ -326,6 +463,24 @@ where
         }
     }
 
+    if let Some(hidden_functions) = matches.values_of("blacklist-function") {
+        for fun in hidden_functions {
+            builder = builder.blacklist_function(fun);
+        }
+    }
+
+    if let Some(hidden_vars) = matches.values_of("blacklist-var") {
+        for var in hidden_vars {
+            builder = builder.blacklist_var(var);
+        }
+    }
+
+    if let Some(hidden_items) = matches.values_of("blacklist-item") {
+        for item in hidden_items {
+            builder = builder.blacklist_item(item);
+        }
+    }
+
     if matches.is_present("builtins") {
         builder = builder.emit_builtins();
     }
@@ -334,6 +489,14 @@ where
         builder = builder.layout_tests(false);
     }
 
+    if matches.is_present("compile-time-layout-tests") {
+        builder = builder.compile_time_layout_tests(true);
+    }
+
+    if matches.is_present("no-fail-on-clang-errors") {
+        builder = builder.fail_on_clang_errors(false);
+    }
+
     if matches.is_present("no-derive-copy") {
         builder = builder.derive_copy(false);
     }
@@ -350,6 +513,10 @@ where
         builder = builder.impl_partialeq(true);
     }
 
+    if matches.is_present("impl-try-from") {
+        builder = builder.impl_try_from(true);
+    }
+
     if matches.is_present("with-derive-default") {
         builder = builder.derive_default(true);
     }
@@ -390,6 +557,10 @@ where
         builder = builder.ctypes_prefix(prefix);
     }
 
+    if let Some(name) = matches.value_of("dynamic-loading") {
+        builder = builder.dynamic_library_name(name);
+    }
+
     if let Some(what_to_generate) = matches.value_of("generate") {
         let mut config = CodegenConfig::nothing();
         for what in what_to_generate.split(",") {
@@ -483,6 +654,55 @@ where
         builder = builder.generate_inline_functions(true);
     }
 
+    if matches.is_present("owned-wrappers") {
+        builder = builder.owned_wrappers(true);
+    }
+
+    if matches.is_present("vtable-generation") {
+        builder = builder.vtable_generation(true);
+    }
+
+    if matches.is_present("translate-function-macros") {
+        builder = builder.translate_function_macros(true);
+    }
+
+    if matches.is_present("raw-identifiers") {
+        builder = builder.raw_identifiers(true);
+    }
+
+    if matches.is_present("stdint-primitives") {
+        builder = builder.stdint_primitives(true);
+    }
+
+    if matches.is_present("enable-function-attribute-detection") {
+        builder = builder.enable_function_attribute_detection(true);
+    }
+
+    if let Some(depfile) = matches.value_of("depfile") {
+        let output = matches.value_of("output").unwrap();
+        builder = builder.depfile(output, depfile);
+    }
+
+    if matches.is_present("wrap-static-fns") {
+        builder = builder.wrap_static_fns(true);
+    }
+
+    if let Some(path) = matches.value_of("wrap-static-fns-path") {
+        builder = builder.wrap_static_fns_path(path);
+    } else if let Some(output) = matches.value_of("output") {
+        builder = builder.wrap_static_fns_path(
+            PathBuf::from(output).with_extension("c"),
+        );
+    }
+
+    if let Some(suffix) = matches.value_of("wrap-static-fns-suffix") {
+        builder = builder.wrap_static_fns_suffix(suffix);
+    }
+
+    if let Some(path) = matches.value_of("c-layout-assertions") {
+        builder = builder.c_layout_assertions(path);
+    }
+
     if let Some(whitelist) = matches.values_of("whitelist-function") {
         for regex in whitelist {
             builder = builder.whitelist_function(regex);
@@ -501,6 +721,18 @@ where
         }
     }
 
+    if let Some(whitelist) = matches.values_of("whitelist-file") {
+        for regex in whitelist {
+            builder = builder.whitelist_file(regex);
+        }
+    }
+
+    if let Some(blacklist) = matches.values_of("blacklist-file") {
+        for regex in blacklist {
+            builder = builder.blacklist_file(regex);
+        }
+    }
+
     if let Some(args) = matches.values_of("clang-args") {
         for arg in args {
             builder = builder.clang_arg(arg);
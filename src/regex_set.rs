@@ -1,6 +1,6 @@
 //! A type that represents the union of a set of regular expressions.
 
-use regex::RegexSet as RxSet;
+use regex::{Error, RegexSet as RxSet};
 
 // Yeah, I'm aware this is sorta crappy, should be cheaper to compile a regex
 // ORing all the patterns, I guess...
@@ -35,13 +35,17 @@ impl RegexSet {
     /// Construct a RegexSet from the set of entries we've accumulated.
     ///
     /// Must be called before calling `matches()`, or it will always return
-    /// false.
-    pub fn build(&mut self) {
-        self.set = match RxSet::new(&self.items) {
-            Ok(x) => Some(x),
+    /// false. Returns an error if any of the entries is not a valid regex.
+    pub fn build(&mut self) -> Result<(), Error> {
+        match RxSet::new(&self.items) {
+            Ok(x) => {
+                self.set = Some(x);
+                Ok(())
+            }
             Err(e) => {
                 error!("Invalid regex in {:?}: {:?}", self.items, e);
-                None
+                self.set = None;
+                Err(e)
             }
         }
     }
@@ -0,0 +1,188 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+pub type atomic_uint = ::std::sync::atomic::AtomicU32;
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Counters {
+    pub hits: ::std::sync::atomic::AtomicI32,
+    pub misses: atomic_uint,
+    pub bytes: ::std::sync::atomic::AtomicI64,
+    pub last: ::std::sync::atomic::AtomicPtr<::std::os::raw::c_void>,
+    pub busy: ::std::sync::atomic::AtomicBool,
+}
+#[test]
+fn bindgen_test_layout_Counters() {
+    assert_eq!(
+        ::std::mem::size_of::<Counters>(),
+        32usize,
+        concat!("Size of: ", stringify!(Counters))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Counters>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Counters))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Counters, hits),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Counters),
+            "::",
+            stringify!(hits)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Counters, misses),
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Counters),
+            "::",
+            stringify!(misses)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Counters, bytes),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Counters),
+            "::",
+            stringify!(bytes)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Counters, last),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Counters),
+            "::",
+            stringify!(last)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Counters, busy),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Counters),
+            "::",
+            stringify!(busy)
+        )
+    );
+}
+pub type my_int_t = ::std::os::raw::c_int;
+pub const state_IDLE: state = 0;
+pub const state_BUSY: state = 1;
+pub type state = u32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Node {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Queue {
+    pub len: ::std::sync::atomic::AtomicI32,
+    pub state: ::std::sync::atomic::AtomicU32,
+    pub head: ::std::sync::atomic::AtomicPtr<Node>,
+    pub cursor: ::std::sync::atomic::AtomicPtr<::std::os::raw::c_int>,
+}
+#[test]
+fn bindgen_test_layout_Queue() {
+    assert_eq!(
+        ::std::mem::size_of::<Queue>(),
+        24usize,
+        concat!("Size of: ", stringify!(Queue))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Queue>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Queue))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Queue, len),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Queue),
+            "::",
+            stringify!(len)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Queue, state),
+        4usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Queue),
+            "::",
+            stringify!(state)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Queue, head),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Queue),
+            "::",
+            stringify!(head)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Queue, cursor),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Queue),
+            "::",
+            stringify!(cursor)
+        )
+    );
+}
+pub type size_t = ::std::os::raw::c_ulong;
+pub type intptr_t = ::std::os::raw::c_long;
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Sizes {
+    pub count: ::std::sync::atomic::AtomicUsize,
+    pub offset: ::std::sync::atomic::AtomicIsize,
+}
+#[test]
+fn bindgen_test_layout_Sizes() {
+    assert_eq!(
+        ::std::mem::size_of::<Sizes>(),
+        16usize,
+        concat!("Size of: ", stringify!(Sizes))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Sizes>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Sizes))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Sizes, count),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Sizes),
+            "::",
+            stringify!(count)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Sizes, offset),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Sizes),
+            "::",
+            stringify!(offset)
+        )
+    );
+}
@@ -0,0 +1,40 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Wanted {
+    pub a: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Wanted() {
+    assert_eq!(
+        ::std::mem::size_of::<Wanted>(),
+        4usize,
+        concat!("Size of: ", stringify!(Wanted))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Wanted>(),
+        4usize,
+        concat!("Alignment of ", stringify!(Wanted))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Wanted>())).a as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Wanted),
+            "::",
+            stringify!(a)
+        )
+    );
+}
+extern "C" {
+    pub fn wanted_function(w: *mut Wanted);
+}
+extern "C" {
+    pub fn defined_in_main_header();
+}
@@ -0,0 +1,13 @@
+/* automatically generated by rust-bindgen */
+
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+
+extern "C" {
+    pub fn kept();
+}
+extern "C" {
+    #[link_name = "\u{1}kept_global"]
+    pub static mut kept_global: ::std::os::raw::c_int;
+}
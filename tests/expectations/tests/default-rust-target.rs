@@ -0,0 +1,44 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub tag: ::std::os::raw::c_char,
+    pub x: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Point() {
+    assert_eq!(
+        ::std::mem::size_of::<Point>(),
+        8usize,
+        concat!("Size of: ", stringify!(Point))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Point>(),
+        4usize,
+        concat!("Alignment of ", stringify!(Point))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Point, tag),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Point),
+            "::",
+            stringify!(tag)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Point, x),
+        4usize,
+        concat!("Offset of field: ", stringify!(Point), "::", stringify!(x))
+    );
+}
+unsafe extern "C" {
+    pub fn distance(a: Point, b: Point) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub static mut origin_count: ::std::os::raw::c_int;
+}
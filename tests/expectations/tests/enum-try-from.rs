@@ -0,0 +1,42 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct __BindgenUnknownEnumValue<T>(pub T);
+pub const Foo_Foo_C: Foo = Foo::Foo_B;
+impl ::std::convert::TryFrom<i32> for Foo {
+    type Error = __BindgenUnknownEnumValue<i32>;
+    #[inline]
+    fn try_from(value: i32) -> ::std::result::Result<Self, Self::Error> {
+        match value {
+            -2 => Ok(Foo::Foo_A),
+            1 => Ok(Foo::Foo_B),
+            _ => Err(__BindgenUnknownEnumValue(value)),
+        }
+    }
+}
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Foo {
+    Foo_A = -2,
+    Foo_B = 1,
+}
+impl ::std::convert::TryFrom<u32> for Bar {
+    type Error = __BindgenUnknownEnumValue<u32>;
+    #[inline]
+    fn try_from(value: u32) -> ::std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Bar::Bar_A),
+            1 => Ok(Bar::Bar_B),
+            _ => Err(__BindgenUnknownEnumValue(value)),
+        }
+    }
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Bar {
+    Bar_A = 0,
+    Bar_B = 1,
+}
@@ -0,0 +1,11 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+extern "C" {
+    #[must_use]
+    pub fn must_use_result(value: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn ignorable_result(value: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
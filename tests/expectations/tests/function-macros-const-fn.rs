@@ -0,0 +1,23 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+pub const VERSION: u32 = 66051;
+pub const fn VERSION_MAJOR(v: u32) -> u32 {
+    (((v) >> 16) & 255)
+}
+pub const fn MAKE_VERSION(major: u32, minor: u32) -> u32 {
+    (((major).wrapping_shl(16)) | ((minor).wrapping_shl(8)))
+}
+pub const fn NEGATE(x: i32) -> i32 {
+    (-(x))
+}
+pub const fn SUB_FROM_LITERAL(x: u32) -> u32 {
+    (100u32.wrapping_sub((x)))
+}
+pub const fn SUB_FROM_MACRO(x: u32) -> u32 {
+    (66051u32.wrapping_sub((x)))
+}
+pub const fn SHIFT_WIDE(x: u64, n: u32) -> u64 {
+    (((x) | 4294967296).wrapping_shl((n as u64) as u32))
+}
@@ -0,0 +1,41 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+pub const VERSION: u32 = 66051;
+#[macro_export]
+macro_rules! VERSION_MAJOR {
+    ($v:expr) => {
+        ((($v) >> 16) & 255)
+    };
+}
+#[macro_export]
+macro_rules! MAKE_VERSION {
+    ($major:expr, $minor:expr) => {
+        ((($major as u32).wrapping_shl(16)) | (($minor as u32).wrapping_shl(8)))
+    };
+}
+#[macro_export]
+macro_rules! NEGATE {
+    ($x:expr) => {
+        (-($x))
+    };
+}
+#[macro_export]
+macro_rules! SUB_FROM_LITERAL {
+    ($x:expr) => {
+        (100u32.wrapping_sub(($x as u32)))
+    };
+}
+#[macro_export]
+macro_rules! SUB_FROM_MACRO {
+    ($x:expr) => {
+        (66051u32.wrapping_sub(($x as u32)))
+    };
+}
+#[macro_export]
+macro_rules! SHIFT_WIDE {
+    ($x:expr, $n:expr) => {
+        ((($x as u64) | 4294967296).wrapping_shl(($n as u64) as u32))
+    };
+}
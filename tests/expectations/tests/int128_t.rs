@@ -1,4 +1,27 @@
 /* automatically generated by rust-bindgen */
 
-
 #![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Foo {
+    pub foo: i128,
+}
+#[test]
+fn bindgen_test_layout_Foo() {
+    assert_eq!(
+        ::std::mem::size_of::<Foo>(),
+        16usize,
+        concat!("Size of: ", stringify!(Foo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Foo>(),
+        16usize,
+        concat!("Alignment of ", stringify!(Foo))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Foo, foo),
+        0usize,
+        concat!("Offset of field: ", stringify!(Foo), "::", stringify!(foo))
+    );
+}
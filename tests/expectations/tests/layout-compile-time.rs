@@ -0,0 +1,30 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub tag: ::std::os::raw::c_char,
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+const _: () = {
+    assert!(::std::mem::size_of::<Point>() == 12usize, "Size of: Point");
+    assert!(
+        ::std::mem::align_of::<Point>() == 4usize,
+        "Alignment of Point"
+    );
+    assert!(
+        ::std::mem::offset_of!(Point, tag) == 0usize,
+        "Offset of field: Point::tag"
+    );
+    assert!(
+        ::std::mem::offset_of!(Point, x) == 4usize,
+        "Offset of field: Point::x"
+    );
+    assert!(
+        ::std::mem::offset_of!(Point, y) == 8usize,
+        "Offset of field: Point::y"
+    );
+};
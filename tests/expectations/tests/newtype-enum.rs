@@ -0,0 +1,19 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+impl Foo {
+    pub const Bar: Foo = Foo(2);
+}
+impl Foo {
+    pub const Baz: Foo = Foo(4);
+}
+impl Foo {
+    pub const Duplicated: Foo = Foo(4);
+}
+impl Foo {
+    pub const Negative: Foo = Foo(-3);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Foo(pub i32);
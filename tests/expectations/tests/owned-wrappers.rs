@@ -0,0 +1,92 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Resource {
+    pub handle: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Resource() {
+    assert_eq!(
+        ::std::mem::size_of::<Resource>(),
+        4usize,
+        concat!("Size of: ", stringify!(Resource))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Resource>(),
+        4usize,
+        concat!("Alignment of ", stringify!(Resource))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Resource>())).handle as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(Resource), "::", stringify!(handle))
+    );
+}
+extern "C" {
+    #[link_name = "\u{1}_ZN8ResourceC1Ev"]
+    pub fn Resource_Resource(this: *mut Resource);
+}
+extern "C" {
+    #[link_name = "\u{1}_ZN8ResourceC1Ei"]
+    pub fn Resource_Resource1(this: *mut Resource, handle: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "\u{1}_ZN8ResourceD1Ev"]
+    pub fn Resource_Resource_destructor(this: *mut Resource);
+}
+impl Resource {
+    #[inline]
+    pub unsafe fn new() -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        Resource_Resource(&mut __bindgen_tmp);
+        __bindgen_tmp
+    }
+    #[inline]
+    pub unsafe fn new1(handle: ::std::os::raw::c_int) -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        Resource_Resource1(&mut __bindgen_tmp, handle);
+        __bindgen_tmp
+    }
+    #[inline]
+    pub unsafe fn destruct(&mut self) {
+        Resource_Resource_destructor(self)
+    }
+}
+/// An owned `Resource`, which is destroyed when dropped.
+pub struct Resource_Owned(pub Resource);
+impl Resource_Owned {
+    #[inline]
+    pub unsafe fn new() -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        Resource_Resource(&mut __bindgen_tmp);
+        Resource_Owned(__bindgen_tmp)
+    }
+    #[inline]
+    pub unsafe fn new1(handle: ::std::os::raw::c_int) -> Self {
+        let mut __bindgen_tmp = ::std::mem::uninitialized();
+        Resource_Resource1(&mut __bindgen_tmp, handle);
+        Resource_Owned(__bindgen_tmp)
+    }
+}
+impl ::std::ops::Deref for Resource_Owned {
+    type Target = Resource;
+    #[inline]
+    fn deref(&self) -> &Resource {
+        &self.0
+    }
+}
+impl ::std::ops::DerefMut for Resource_Owned {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Resource {
+        &mut self.0
+    }
+}
+impl Drop for Resource_Owned {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { Resource_Resource_destructor(&mut self.0) }
+    }
+}
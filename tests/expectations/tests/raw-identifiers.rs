@@ -0,0 +1,44 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct foo {
+    pub r#type: ::std::os::raw::c_int,
+    pub r#match: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_foo() {
+    assert_eq!(
+        ::std::mem::size_of::<foo>(),
+        8usize,
+        concat!("Size of: ", stringify!(foo))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<foo>(),
+        4usize,
+        concat!("Alignment of ", stringify!(foo))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<foo>())).r#type as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(foo), "::", stringify!(r#type))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<foo>())).r#match as *const _ as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(foo), "::", stringify!(r#match))
+    );
+}
+pub const kind_type: kind = 0;
+pub const kind_self_: kind = 1;
+pub type kind = u32;
+extern "C" {
+    #[link_name = "\u{1}match"]
+    pub static mut r#match: ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}move"]
+    pub fn r#move(r#fn: ::std::os::raw::c_int);
+}
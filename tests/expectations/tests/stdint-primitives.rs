@@ -0,0 +1,68 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Buffer {
+    pub flags: u32,
+    pub offset: i64,
+    pub len: usize,
+    pub last_read: isize,
+}
+#[test]
+fn bindgen_test_layout_Buffer() {
+    assert_eq!(
+        ::std::mem::size_of::<Buffer>(),
+        32usize,
+        concat!("Size of: ", stringify!(Buffer))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Buffer>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Buffer))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Buffer, flags),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Buffer),
+            "::",
+            stringify!(flags)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Buffer, offset),
+        8usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Buffer),
+            "::",
+            stringify!(offset)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Buffer, len),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Buffer),
+            "::",
+            stringify!(len)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Buffer, last_read),
+        24usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Buffer),
+            "::",
+            stringify!(last_read)
+        )
+    );
+}
+extern "C" {
+    pub fn buffer_len(buffer: *const Buffer) -> usize;
+}
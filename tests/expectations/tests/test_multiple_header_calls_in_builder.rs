@@ -1,6 +1,6 @@
 /* automatically generated by rust-bindgen */
 
-extern "C" {
+unsafe extern "C" {
     #[link_name = "\u{1}foo"]
     pub static mut foo:
         ::std::option::Option<
@@ -40,7 +40,7 @@ fn bindgen_test_layout_Test() {
         concat!("Alignment of ", stringify!(Test))
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).ch as *const _ as usize },
+        ::std::mem::offset_of!(Test, ch),
         0usize,
         concat!(
             "Offset of field: ",
@@ -50,7 +50,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).u as *const _ as usize },
+        ::std::mem::offset_of!(Test, u),
         1usize,
         concat!(
             "Offset of field: ",
@@ -60,7 +60,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).d as *const _ as usize },
+        ::std::mem::offset_of!(Test, d),
         2usize,
         concat!(
             "Offset of field: ",
@@ -70,7 +70,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).cch as *const _ as usize },
+        ::std::mem::offset_of!(Test, cch),
         3usize,
         concat!(
             "Offset of field: ",
@@ -80,7 +80,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).cu as *const _ as usize },
+        ::std::mem::offset_of!(Test, cu),
         4usize,
         concat!(
             "Offset of field: ",
@@ -90,7 +90,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).cd as *const _ as usize },
+        ::std::mem::offset_of!(Test, cd),
         5usize,
         concat!(
             "Offset of field: ",
@@ -100,7 +100,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Cch as *const _ as usize },
+        ::std::mem::offset_of!(Test, Cch),
         6usize,
         concat!(
             "Offset of field: ",
@@ -110,7 +110,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Cu as *const _ as usize },
+        ::std::mem::offset_of!(Test, Cu),
         7usize,
         concat!(
             "Offset of field: ",
@@ -120,7 +120,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Cd as *const _ as usize },
+        ::std::mem::offset_of!(Test, Cd),
         8usize,
         concat!(
             "Offset of field: ",
@@ -130,7 +130,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Ccch as *const _ as usize },
+        ::std::mem::offset_of!(Test, Ccch),
         9usize,
         concat!(
             "Offset of field: ",
@@ -140,7 +140,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Ccu as *const _ as usize },
+        ::std::mem::offset_of!(Test, Ccu),
         10usize,
         concat!(
             "Offset of field: ",
@@ -150,7 +150,7 @@ fn bindgen_test_layout_Test() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::std::ptr::null::<Test>())).Ccd as *const _ as usize },
+        ::std::mem::offset_of!(Test, Ccd),
         11usize,
         concat!(
             "Offset of field: ",
@@ -0,0 +1,88 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_Point() {
+    assert_eq!(
+        ::std::mem::size_of::<Point>(),
+        8usize,
+        concat!("Size of: ", stringify!(Point))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Point>(),
+        4usize,
+        concat!("Alignment of ", stringify!(Point))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Point>())).x as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(Point), "::", stringify!(x))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Point>())).y as *const _ as usize },
+        4usize,
+        concat!("Offset of field: ", stringify!(Point), "::", stringify!(y))
+    );
+}
+#[repr(C)]
+pub union Value {
+    pub integer: ::std::os::raw::c_int,
+    pub pointer: *mut ::std::os::raw::c_int,
+    pub point: ::std::mem::ManuallyDrop<Point>,
+    _bindgen_union_align: u64,
+}
+#[test]
+fn bindgen_test_layout_Value() {
+    assert_eq!(
+        ::std::mem::size_of::<Value>(),
+        8usize,
+        concat!("Size of: ", stringify!(Value))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Value>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Value))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Value>())).integer as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Value),
+            "::",
+            stringify!(integer)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Value>())).pointer as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Value),
+            "::",
+            stringify!(pointer)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<Value>())).point as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Value),
+            "::",
+            stringify!(point)
+        )
+    );
+}
+impl Default for Value {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
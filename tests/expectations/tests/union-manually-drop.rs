@@ -0,0 +1,83 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct HasDtor {
+    pub member: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout_HasDtor() {
+    assert_eq!(
+        ::std::mem::size_of::<HasDtor>(),
+        4usize,
+        concat!("Size of: ", stringify!(HasDtor))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<HasDtor>(),
+        4usize,
+        concat!("Alignment of ", stringify!(HasDtor))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<HasDtor>())).member as *const _ as usize },
+        0usize,
+        concat!("Offset of field: ", stringify!(HasDtor), "::", stringify!(member))
+    );
+}
+extern "C" {
+    #[link_name = "\u{1}_ZN7HasDtorD1Ev"]
+    pub fn HasDtor_HasDtor_destructor(this: *mut HasDtor);
+}
+impl HasDtor {
+    #[inline]
+    pub unsafe fn destruct(&mut self) {
+        HasDtor_HasDtor_destructor(self)
+    }
+}
+#[repr(C)]
+pub union UnionWithNonCopyMember {
+    pub plain: ::std::os::raw::c_int,
+    pub non_copy: ::std::mem::ManuallyDrop<HasDtor>,
+    _bindgen_union_align: u32,
+}
+#[test]
+fn bindgen_test_layout_UnionWithNonCopyMember() {
+    assert_eq!(
+        ::std::mem::size_of::<UnionWithNonCopyMember>(),
+        4usize,
+        concat!("Size of: ", stringify!(UnionWithNonCopyMember))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<UnionWithNonCopyMember>(),
+        4usize,
+        concat!("Alignment of ", stringify!(UnionWithNonCopyMember))
+    );
+    assert_eq!(
+        unsafe { &(*(::std::ptr::null::<UnionWithNonCopyMember>())).plain as *const _ as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(UnionWithNonCopyMember),
+            "::",
+            stringify!(plain)
+        )
+    );
+    assert_eq!(
+        unsafe {
+            &(*(::std::ptr::null::<UnionWithNonCopyMember>())).non_copy as *const _ as usize
+        },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(UnionWithNonCopyMember),
+            "::",
+            stringify!(non_copy)
+        )
+    );
+}
+impl Default for UnionWithNonCopyMember {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
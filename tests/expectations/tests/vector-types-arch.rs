@@ -0,0 +1,42 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+pub type __m128 = ::std::arch::x86_64::__m128;
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct v4si(pub [::std::os::raw::c_int; 4usize]);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Registers {
+    pub xmm: __m128,
+}
+#[test]
+fn bindgen_test_layout_Registers() {
+    assert_eq!(
+        ::std::mem::size_of::<Registers>(),
+        16usize,
+        concat!("Size of: ", stringify!(Registers))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Registers>(),
+        16usize,
+        concat!("Alignment of ", stringify!(Registers))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Registers, xmm),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Registers),
+            "::",
+            stringify!(xmm)
+        )
+    );
+}
+impl Default for Registers {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
@@ -0,0 +1,62 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct v4si(pub [::std::os::raw::c_int; 4usize]);
+#[repr(C)]
+#[repr(align(32))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct v8sf(pub [f32; 8usize]);
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Samples {
+    pub floats: v8sf,
+    pub ints: v4si,
+    pub counts: [::std::os::raw::c_int; 4usize],
+}
+#[test]
+fn bindgen_test_layout_Samples() {
+    assert_eq!(
+        ::std::mem::size_of::<Samples>(),
+        64usize,
+        concat!("Size of: ", stringify!(Samples))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Samples>(),
+        32usize,
+        concat!("Alignment of ", stringify!(Samples))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Samples, floats),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Samples),
+            "::",
+            stringify!(floats)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Samples, ints),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Samples),
+            "::",
+            stringify!(ints)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(Samples, counts),
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(Samples),
+            "::",
+            stringify!(counts)
+        )
+    );
+}
@@ -0,0 +1,54 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+pub struct Shape__bindgen_vtable {
+    pub sides: unsafe extern "C" fn(this: *const Shape) -> ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Shape {
+    pub vtable_: *const Shape__bindgen_vtable,
+}
+#[test]
+fn bindgen_test_layout_Shape() {
+    assert_eq!(
+        ::std::mem::size_of::<Shape>(),
+        8usize,
+        concat!("Size of: ", stringify!(Shape))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Shape>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Shape))
+    );
+}
+impl Default for Shape {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Polygon {
+    pub _base: Shape,
+}
+#[test]
+fn bindgen_test_layout_Polygon() {
+    assert_eq!(
+        ::std::mem::size_of::<Polygon>(),
+        8usize,
+        concat!("Size of: ", stringify!(Polygon))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Polygon>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Polygon))
+    );
+}
+impl Default for Polygon {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
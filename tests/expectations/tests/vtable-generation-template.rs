@@ -0,0 +1,18 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+pub struct Visitor__bindgen_vtable(::std::os::raw::c_void);
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Visitor<T> {
+    pub vtable_: *const Visitor__bindgen_vtable,
+    pub value: T,
+    pub _phantom_0: ::std::marker::PhantomData<::std::cell::UnsafeCell<T>>,
+}
+impl<T> Default for Visitor<T> {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
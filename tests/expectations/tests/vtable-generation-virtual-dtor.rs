@@ -0,0 +1,33 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+pub struct Resource__bindgen_vtable(::std::os::raw::c_void);
+#[repr(C)]
+#[derive(Debug)]
+pub struct Resource {
+    pub vtable_: *const Resource__bindgen_vtable,
+}
+#[test]
+fn bindgen_test_layout_Resource() {
+    assert_eq!(
+        ::std::mem::size_of::<Resource>(),
+        8usize,
+        concat!("Size of: ", stringify!(Resource))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<Resource>(),
+        8usize,
+        concat!("Alignment of ", stringify!(Resource))
+    );
+}
+impl Default for Resource {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
+extern "C" {
+    #[link_name = "\u{1}_ZN8ResourceD1Ev"]
+    pub fn Resource_Resource_destructor(this: *mut Resource);
+}
@@ -0,0 +1,41 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[repr(C)]
+pub struct PluginInterface__bindgen_vtable {
+    pub version: unsafe extern "C" fn(this: *const PluginInterface) -> ::std::os::raw::c_int,
+    pub process: unsafe extern "C" fn(
+        this: *mut PluginInterface,
+        samples: *mut f32,
+        count: ::std::os::raw::c_int,
+    ),
+    pub process1: unsafe extern "C" fn(
+        this: *mut PluginInterface,
+        samples: *mut f64,
+        count: ::std::os::raw::c_int,
+    ),
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct PluginInterface {
+    pub vtable_: *const PluginInterface__bindgen_vtable,
+}
+#[test]
+fn bindgen_test_layout_PluginInterface() {
+    assert_eq!(
+        ::std::mem::size_of::<PluginInterface>(),
+        8usize,
+        concat!("Size of: ", stringify!(PluginInterface))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<PluginInterface>(),
+        8usize,
+        concat!("Alignment of ", stringify!(PluginInterface))
+    );
+}
+impl Default for PluginInterface {
+    fn default() -> Self {
+        unsafe { ::std::mem::zeroed() }
+    }
+}
@@ -0,0 +1,71 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+#[derive(PartialEq, Copy, Clone, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct __BindgenFloat16(pub u16);
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct WideScalars {
+    pub big: i128,
+    pub ubig: u128,
+    pub extended: u128,
+    pub halves: [__BindgenFloat16; 8usize],
+}
+#[test]
+fn bindgen_test_layout_WideScalars() {
+    assert_eq!(
+        ::std::mem::size_of::<WideScalars>(),
+        64usize,
+        concat!("Size of: ", stringify!(WideScalars))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<WideScalars>(),
+        16usize,
+        concat!("Alignment of ", stringify!(WideScalars))
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(WideScalars, big),
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WideScalars),
+            "::",
+            stringify!(big)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(WideScalars, ubig),
+        16usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WideScalars),
+            "::",
+            stringify!(ubig)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(WideScalars, extended),
+        32usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WideScalars),
+            "::",
+            stringify!(extended)
+        )
+    );
+    assert_eq!(
+        ::std::mem::offset_of!(WideScalars, halves),
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(WideScalars),
+            "::",
+            stringify!(halves)
+        )
+    );
+}
+extern "C" {
+    pub fn widen(value: ::std::os::raw::c_longlong) -> i128;
+}
@@ -0,0 +1,32 @@
+/* automatically generated by rust-bindgen */
+
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+
+extern "C" {
+    #[link_name = "foo__extern"]
+    pub fn foo() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "bar__extern"]
+    pub fn bar() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "baz__extern"]
+    pub fn baz() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "takes_ptr__extern"]
+    pub fn takes_ptr(arg: *mut ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "takes_fn_ptr__extern"]
+    pub fn takes_fn_ptr(
+        f: ::std::option::Option<
+            unsafe extern "C" fn(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int,
+        >,
+    ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "nevermore__extern"]
+    pub fn nevermore();
+}
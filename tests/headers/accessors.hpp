@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct SomeAccessors {
     int mNoAccessor;
     /** <div rustbindgen accessor></div> */
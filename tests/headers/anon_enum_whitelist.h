@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-var NODE_.* --rustified-enum .*
+// bindgen-flags: --rust-target 1.21 --whitelist-var NODE_.* --rustified-enum .*
 
 enum {
   NODE_FLAG_FOO,
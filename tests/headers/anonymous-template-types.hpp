@@ -1,4 +1,4 @@
-// bindgen-flags:  --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++14
 
 template <typename T, typename>
 struct Foo {
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // This comment exists to ensure that `--dump-preprocessed-input` doesn't strip
 // comments.
 void foo(const char* type);
@@ -0,0 +1,30 @@
+// bindgen-flags: --rust-target 1.77
+
+typedef _Atomic(unsigned int) atomic_uint;
+
+struct Counters {
+  _Atomic int hits;
+  atomic_uint misses;
+  _Atomic(long long) bytes;
+  _Atomic(void *) last;
+  _Atomic _Bool busy;
+};
+
+typedef int my_int_t;
+enum state { IDLE, BUSY };
+struct Node;
+
+struct Queue {
+  _Atomic(my_int_t) len;
+  _Atomic(enum state) state;
+  _Atomic(struct Node *) head;
+  _Atomic(const int *) cursor;
+};
+
+typedef unsigned long size_t;
+typedef long intptr_t;
+
+struct Sizes {
+  _Atomic(size_t) count;
+  _Atomic(intptr_t) offset;
+};
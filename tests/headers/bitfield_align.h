@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct A {
   unsigned char x;
   unsigned b1 : 1;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef struct
 {
   unsigned int pad3: 24;
@@ -1,4 +1,4 @@
-// bindgen-flags: --impl-debug --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
+// bindgen-flags: --rust-target 1.21 --impl-debug --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
 
 struct BlacklistMe {};
 
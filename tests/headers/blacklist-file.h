@@ -0,0 +1,6 @@
+// bindgen-flags: --rust-target 1.21 --blacklist-file '.*/unwanted\.h' -- -Itests/headers/whitelist-file
+
+#include "wanted.h"
+#include "unwanted.h"
+
+void defined_in_main_header(void);
@@ -0,0 +1,8 @@
+// bindgen-flags: --rust-target 1.21 --blacklist-function 'broken_.*' --blacklist-var 'hidden_global' --blacklist-item 'also_hidden'
+
+void broken_prototype(int a);
+void also_hidden(void);
+void kept(void);
+
+extern int hidden_global;
+extern int kept_global;
@@ -1,3 +1,3 @@
-// bindgen-flags: -- -fblocks
+// bindgen-flags: --rust-target 1.21 -- -fblocks
 
 void atexit_b(void (^)(void));
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 namespace std {
 template<class T, T... Ints>
 class integer_sequence;
@@ -1,4 +1,4 @@
-// bindgen-flags: --raw-line "#![cfg(not(test))]" -- --target=i686-pc-win32
+// bindgen-flags: --rust-target 1.21 --raw-line "#![cfg(not(test))]" -- --target=i686-pc-win32
 // bindgen-unstable
 //
 // We can only check that this builds, but not that it actually passes, because
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --with-derive-partialeq --with-derive-eq
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --with-derive-partialeq --with-derive-eq
 float _Complex globalValueFloat;
 double _Complex globalValueDouble;
 long double _Complex globalValueLongDouble;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef unsigned char uint8_t;
 
 void foo(const uint8_t* foo);
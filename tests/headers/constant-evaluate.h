@@ -1,5 +1,5 @@
 // bindgen-unstable
-// bindgen-flags: --rustified-enum .*
+// bindgen-flags: --rust-target 1.21 --rustified-enum .*
 
 enum {
   foo = 4,
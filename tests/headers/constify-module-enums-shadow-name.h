@@ -1,4 +1,4 @@
-// bindgen-flags: --constified-enum-module foo
+// bindgen-flags: --rust-target 1.21 --constified-enum-module foo
 
 enum foo {
   Type,
@@ -1,4 +1,4 @@
-// bindgen-flags: --constified-enum-module Foo
+// bindgen-flags: --rust-target 1.21 --constified-enum-module Foo
 
 enum class Foo {
   Variant1, Variant2, Variant3,
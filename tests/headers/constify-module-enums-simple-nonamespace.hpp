@@ -1,4 +1,4 @@
-// bindgen-flags: --constified-enum-module one::Foo
+// bindgen-flags: --rust-target 1.21 --constified-enum-module one::Foo
 
 namespace one {
   enum class Foo {
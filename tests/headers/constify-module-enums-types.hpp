@@ -1,4 +1,4 @@
-// bindgen-flags: --constified-enum-module .*
+// bindgen-flags: --rust-target 1.21 --constified-enum-module .*
 
 typedef enum foo {
   THIS,
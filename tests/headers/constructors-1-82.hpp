@@ -0,0 +1,6 @@
+// bindgen-flags: --rust-target 1.82 --use-core --raw-line "extern crate core;"
+
+class Foo {
+ public:
+  Foo(int a);
+};
@@ -1,4 +1,4 @@
-// bindgen-flags: --no-convert-floats
+// bindgen-flags: --rust-target 1.21 --no-convert-floats
 
 struct foo {
   float bar, baz;
@@ -1,4 +1,4 @@
-// bindgen-flags: -- -x c++ --std=c++11
+// bindgen-flags: --rust-target 1.21 -- -x c++ --std=c++11
 
 template<typename T>
 struct Foo {
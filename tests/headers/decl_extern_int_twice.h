@@ -1,2 +1,4 @@
+// bindgen-flags: --rust-target 1.21
+
 extern int foo;
 extern int foo;
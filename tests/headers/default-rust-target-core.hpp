@@ -0,0 +1,9 @@
+// bindgen-flags: --use-core --raw-line "extern crate core;"
+
+// Uses the default Rust target, without `--rust-target`.
+
+class Foo {
+ public:
+  int value;
+  Foo(int a);
+};
@@ -0,0 +1,10 @@
+// Uses the default Rust target, without `--rust-target`.
+
+struct Point {
+  char tag;
+  int x;
+};
+
+int distance(struct Point a, struct Point b);
+
+extern int origin_count;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template <typename T, typename U = int>
 struct Foo {
     T t;
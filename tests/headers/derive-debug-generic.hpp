@@ -1,4 +1,4 @@
-// bindgen-flags: --impl-debug
+// bindgen-flags: --rust-target 1.21 --impl-debug
 
 template<typename T>
 class Generic {
@@ -1,4 +1,4 @@
-// bindgen-flags: --impl-debug
+// bindgen-flags: --rust-target 1.21 --impl-debug
 struct perf_event_attr {
 
     /*
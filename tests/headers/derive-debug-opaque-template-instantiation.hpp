@@ -1,4 +1,4 @@
-// bindgen-flags: --impl-debug
+// bindgen-flags: --rust-target 1.21 --impl-debug
 
 // This type is opaque because the second template parameter
 // is a non-type template parameter
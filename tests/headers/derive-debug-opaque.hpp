@@ -1,4 +1,4 @@
-// bindgen-flags: --opaque-type "Opaque" --impl-debug
+// bindgen-flags: --rust-target 1.21 --opaque-type "Opaque" --impl-debug
 
 class Opaque {
   int i;
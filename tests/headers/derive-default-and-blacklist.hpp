@@ -1,4 +1,4 @@
-// bindgen-flags: --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
+// bindgen-flags: --rust-target 1.21 --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
 
 // Note that we do not explicitly provide the `--with-derive-default` flag
 // above, since it is added by the test runner implicitly.
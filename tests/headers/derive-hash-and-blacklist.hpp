@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --blacklist-type BlacklistMe --raw-line 'pub struct BlacklistMe(u8);'
 
 struct BlacklistMe {};
 
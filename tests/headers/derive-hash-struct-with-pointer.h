@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --with-derive-partialord --with-derive-ord --with-derive-partialeq --with-derive-eq
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --with-derive-partialord --with-derive-ord --with-derive-partialeq --with-derive-eq
 //
 /// Pointers can derive Hash/PartialOrd/Ord/PartialEq/Eq
 struct ConstPtrMutObj {
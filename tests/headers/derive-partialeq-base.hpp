@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-partialeq --impl-partialeq
+// bindgen-flags: --rust-target 1.21 --with-derive-partialeq --impl-partialeq
 
 class Base {
     int large[33];
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-partialeq --impl-partialeq --use-core --raw-line "extern crate core;"
+// bindgen-flags: --rust-target 1.21 --with-derive-partialeq --impl-partialeq --use-core --raw-line "extern crate core;"
 
 struct C {
     int large_array[420];
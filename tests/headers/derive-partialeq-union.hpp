@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-partialeq --impl-partialeq
+// bindgen-flags: --rust-target 1.21 --with-derive-partialeq --impl-partialeq
 
 /// Deriving PartialEq for rust unions is not supported.
 union ShouldNotDerivePartialEq {
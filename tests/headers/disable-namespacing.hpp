@@ -1,4 +1,4 @@
-// bindgen-flags: --disable-name-namespacing
+// bindgen-flags: --rust-target 1.21 --disable-name-namespacing
 
 namespace foo {
 namespace bar {
@@ -1,4 +1,4 @@
-// bindgen-flags: --no-layout-tests
+// bindgen-flags: --rust-target 1.21 --no-layout-tests
 //
 // Unfortunately, we aren't translating the second and third structs correctly
 // yet. But we definitely shouldn't divide-by-zero when we see it...
@@ -1,4 +1,4 @@
-// bindgen-flags: --no-derive-copy
+// bindgen-flags: --rust-target 1.21 --no-derive-copy
 
 class WouldBeCopyButWeAreNotDerivingCopy {
     int x;
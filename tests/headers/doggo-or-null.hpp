@@ -1,4 +1,4 @@
-// bindgen-flags: --opaque-type DoggoOrNull --with-derive-partialeq --with-derive-hash -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --opaque-type DoggoOrNull --with-derive-partialeq --with-derive-hash -- -std=c++14
 
 class Doggo {
     int x;
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces
 
 namespace foo {
   class Bar;
@@ -0,0 +1,3 @@
+// bindgen-flags: --rust-target 1.21 --dynamic-loading TestLib -- --target=x86_64-apple-darwin
+
+int foo(int x);
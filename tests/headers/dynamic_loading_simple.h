@@ -0,0 +1,6 @@
+// bindgen-flags: --rust-target 1.21 --dynamic-loading TestLib
+
+int foo(int x, int y);
+int bar(void *x);
+int baz();
+int qux(int x, ...);
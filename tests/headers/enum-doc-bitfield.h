@@ -1,3 +1,3 @@
-// bindgen-flags: --bitfield-enum B
+// bindgen-flags: --rust-target 1.21 --bitfield-enum B
 
 #include "enum-doc.h"
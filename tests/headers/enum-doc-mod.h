@@ -1,3 +1,3 @@
-// bindgen-flags: --constified-enum-module B
+// bindgen-flags: --rust-target 1.21 --constified-enum-module B
 
 #include "enum-doc.h"
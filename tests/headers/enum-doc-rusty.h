@@ -1,3 +1,3 @@
-// bindgen-flags: --rustified-enum B
+// bindgen-flags: --rust-target 1.21 --rustified-enum B
 
 #include "enum-doc.h"
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 /** Document enum */
 enum B {
     /// Document field with three slashes
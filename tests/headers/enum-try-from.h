@@ -0,0 +1,12 @@
+// bindgen-flags: --rustified-enum Foo --rustified-non-exhaustive-enum Bar --impl-try-from --rust-target 1.40
+
+enum Foo {
+    Foo_A = -2,
+    Foo_B = 1,
+    Foo_C = 1,
+};
+
+enum Bar {
+    Bar_A,
+    Bar_B,
+};
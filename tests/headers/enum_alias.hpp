@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum .* -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --rustified-enum .* -- -std=c++11
 
 typedef unsigned char uint8_t;
 
@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum .* -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --rustified-enum .* -- -std=c++11
 
 namespace std {
   template <typename Char> class fbstring_core;
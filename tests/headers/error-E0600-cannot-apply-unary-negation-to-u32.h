@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef unsigned int uint32_t;
 
 uint32_t a = 18446744073709551611;
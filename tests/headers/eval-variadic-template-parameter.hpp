@@ -1,4 +1,4 @@
-// bindgen-flags: -- -std=c++11
+// bindgen-flags: --rust-target 1.21 -- -std=c++11
 
 template <typename... T>
 struct B {
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 extern "C" {
 #include "func_proto.h"
 }
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // FIXME: libclang < 3.9 does not expose `__float128` in its interface, so this
 // test will fail. Once we remove support for `--features llvm_stable` and
 // require libclang >= 3.9, we can reenable this test.
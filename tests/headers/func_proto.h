@@ -1 +1,3 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef int foo(int bar);
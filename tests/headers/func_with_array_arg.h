@@ -1 +1,3 @@
+// bindgen-flags: --rust-target 1.21
+
 void f(int x[2]);
@@ -1 +1,3 @@
+// bindgen-flags: --rust-target 1.21
+
 void foo(void (*bar)());
@@ -0,0 +1,4 @@
+// bindgen-flags: --rust-target 1.27 --enable-function-attribute-detection
+
+__attribute__((warn_unused_result)) int must_use_result(int value);
+int ignorable_result(int value);
@@ -0,0 +1,11 @@
+// bindgen-flags: --translate-function-macros --rust-target 1.34
+
+#define VERSION 0x010203
+#define VERSION_MAJOR(v) (((v) >> 16) & 0xff)
+#define MAKE_VERSION(major, minor) (((major) << 16) | ((minor) << 8))
+#define NEGATE(x) (-(x))
+#define IS_ZERO(x) ((x) == 0)
+#define LOG(fmt, ...) printf(fmt, __VA_ARGS__)
+#define SUB_FROM_LITERAL(x) (100 - (x))
+#define SUB_FROM_MACRO(x) (VERSION - (x))
+#define SHIFT_WIDE(x, n) (((x) | 0x100000000) << (n))
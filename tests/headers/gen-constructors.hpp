@@ -1,4 +1,4 @@
-// bindgen-flags: --generate types,constructors,functions
+// bindgen-flags: --rust-target 1.21 --generate types,constructors,functions
 
 class Foo {
  public:
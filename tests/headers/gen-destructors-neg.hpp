@@ -1,4 +1,4 @@
-// bindgen-flags: --generate types,functions
+// bindgen-flags: --rust-target 1.21 --generate types,functions
 //
 // NB: This is intended to _not_ generate destructors.
 
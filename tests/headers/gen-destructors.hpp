@@ -1,4 +1,4 @@
-// bindgen-flags: --generate types,destructors,functions
+// bindgen-flags: --rust-target 1.21 --generate types,destructors,functions
 
 class Foo {
   int bar;
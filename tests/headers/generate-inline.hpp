@@ -1,4 +1,4 @@
-// bindgen-flags: --generate-inline-functions
+// bindgen-flags: --rust-target 1.21 --generate-inline-functions
 
 class Foo {
  public:
@@ -1,2 +1,4 @@
+// bindgen-flags: --rust-target 1.21
+
 #define INFINITY (1.0f/0.0f)
 #define NAN (0.0f/0.0f)
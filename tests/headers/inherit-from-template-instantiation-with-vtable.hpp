@@ -1,4 +1,4 @@
-// bindgen-flags: -- -std=c++14
+// bindgen-flags: --rust-target 1.21 -- -std=c++14
 
 // Small test that we handle virtual tables correctly when deriving from a
 // template instantiation. This wasn't previously handled at all. Note that when
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // bindgen-unstable
 
 /** The point of this test is to _not_ generate these functions. */
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces -- -std=c++11
 
 namespace foo {
   inline namespace bar {
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type=std::string -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type=std::string -- -std=c++11
 
 namespace std {
   inline namespace bar {
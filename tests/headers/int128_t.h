@@ -1,7 +1,5 @@
-/**
- * FIXME: Uncomment this once we can generate the proper alignment for the type,
- * i.e., when we use u128/i128.
+// bindgen-flags: --rust-target 1.77
+
 struct Foo {
   __int128 foo;
 };
- */
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 
 template <typename> class a {
   enum {};
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 
 struct S2 {
     unsigned : 11
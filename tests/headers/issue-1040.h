@@ -1 +1,3 @@
+// bindgen-flags: --rust-target 1.21
+
 unsigned long long g_107 = 18446744073709551615UL;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct S1 {
     signed : 15;
     unsigned : 6
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template<class K, class V>
 class Entry : public K
 {
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template <class> class nsTArray;
 template <class b> using c = nsTArray<b>;
 class nsTArray_base {
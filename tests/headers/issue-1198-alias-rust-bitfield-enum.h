@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum '.*' --bitfield-enum '.*'
+// bindgen-flags: --rust-target 1.21 --rustified-enum '.*' --bitfield-enum '.*'
 
 typedef enum MyDupeEnum {
 	A = 0,
@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum '.*' --constified-enum-module '.*'
+// bindgen-flags: --rust-target 1.21 --rustified-enum '.*' --constified-enum-module '.*'
 
 typedef enum MyDupeEnum {
 	A = 0,
@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum '.*'
+// bindgen-flags: --rust-target 1.21 --rustified-enum '.*'
 
 
 typedef enum MyDupeEnum {
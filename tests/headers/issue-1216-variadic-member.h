@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 void f(int a, ...);
 struct Foo {
   void (*f)(void *p, void *obj, int a, ...);
@@ -1,3 +1,3 @@
-// bindgen-flags: --no-copy MyType
+// bindgen-flags: --rust-target 1.21 --no-copy MyType
 
 typedef struct MyType MyTypeT;
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --no-recursive-whitelist --whitelist-type "foo"
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --no-recursive-whitelist --whitelist-type "foo"
 
 struct foo {
     union {
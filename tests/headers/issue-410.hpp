@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type JS::Value --rustified-enum .*
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type JS::Value --rustified-enum .*
 
 namespace JS {
 class Value;
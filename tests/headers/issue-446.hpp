@@ -1,4 +1,4 @@
-// bindgen-flags: -- -std=c++14
+// bindgen-flags: --rust-target 1.21 -- -std=c++14
 
 template <typename Elem>
 class List {
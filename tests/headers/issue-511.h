@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 char * a;
 const char * b;
 char * const c;
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 /// This should not be opaque; we can see the attributes and can pack the
 /// struct.
 struct AlignedToOne {
@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum .* -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --rustified-enum .* -- -std=c++14
 
 // Generated by C-Reduce, cleaned up and given names for readability.
 
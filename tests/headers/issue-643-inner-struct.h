@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct rte_ring {
   struct rte_memzone *memzone;
 
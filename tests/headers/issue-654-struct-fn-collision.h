@@ -1,2 +1,4 @@
+// bindgen-flags: --rust-target 1.21
+
 struct foo;
 int foo(void);
@@ -1,4 +1,4 @@
-// bindgen-flags: --blacklist-type RefPtr --raw-line '#[derive(Clone, Copy, Debug)] pub struct RefPtr<T>(T);' -- --std=c++14
+// bindgen-flags: --rust-target 1.21 --blacklist-type RefPtr --raw-line '#[derive(Clone, Copy, Debug)] pub struct RefPtr<T>(T);' -- --std=c++14
 
 // This is pretty much the same as the other issue 662 test case, but this time
 // we blacklist RefPtr to exercise the instantiation-of-a-blacklisted-template
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type nsCSSValue --opaque-type 'nsRefPtrHashtable' -- -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type nsCSSValue --opaque-type 'nsRefPtrHashtable' -- -- -std=c++14
 
 template <class PtrType> class nsRefPtrHashtable {
   typedef PtrType *UserDataType;
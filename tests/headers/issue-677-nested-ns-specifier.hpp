@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces -- -std=c++14
 
 namespace foo::bar {
   typedef int bar;
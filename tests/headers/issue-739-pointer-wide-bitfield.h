@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 #define POINTER_WIDTH (sizeof(void*) * 8)
 
 struct Foo {
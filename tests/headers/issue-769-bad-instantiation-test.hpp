@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type Rooted
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type Rooted
 
 template <typename T>
 class Rooted {
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef struct {
     unsigned int bit_1 : 1;
     unsigned int bit_2 : 1;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template<typename E, int N>
 class Foo {
     typedef Foo<E, N> self_type;
@@ -1,4 +1,4 @@
-// bindgen-flags: --ignore-methods -- --target=i686-pc-win32
+// bindgen-flags: --rust-target 1.21 --ignore-methods -- --target=i686-pc-win32
 
 struct Foo {
     void test();
@@ -1,4 +1,4 @@
-// bindgen-flags: --generate functions --whitelist-function func --raw-line "#[repr(C)] pub struct nsTArray { pub hdr: *const () }"
+// bindgen-flags: --rust-target 1.21 --generate functions --whitelist-function func --raw-line "#[repr(C)] pub struct nsTArray { pub hdr: *const () }"
 
 template<typename T>
 class nsTArray {
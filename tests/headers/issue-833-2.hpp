@@ -1,4 +1,4 @@
-// bindgen-flags: --raw-line "// If the output of this changes, please ensure issue-833-1.hpp changes too"
+// bindgen-flags: --rust-target 1.21 --raw-line "// If the output of this changes, please ensure issue-833-1.hpp changes too"
 
 template<typename T>
 class nsTArray {
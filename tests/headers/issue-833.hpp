@@ -1,4 +1,4 @@
-// bindgen-flags: --generate functions --whitelist-function func --raw-line "#[repr(C)] pub struct nsTArray<T> { pub hdr: *const T }"
+// bindgen-flags: --rust-target 1.21 --generate functions --whitelist-function func --raw-line "#[repr(C)] pub struct nsTArray<T> { pub hdr: *const T }"
 
 template<typename T>
 class nsTArray {
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type U --generate types
+// bindgen-flags: --rust-target 1.21 --whitelist-type U --generate types
 
 struct T {};
 struct U {
@@ -1,4 +1,4 @@
-// bindgen-flags: -- -Itests/headers/issue-848
+// bindgen-flags: --rust-target 1.21 -- -Itests/headers/issue-848
 
 #include "an-include.h"
 
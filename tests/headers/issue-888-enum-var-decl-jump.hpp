@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --rustified-enum .*
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --rustified-enum .*
 
 namespace Halide {
 struct Type;
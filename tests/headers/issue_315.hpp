@@ -1,2 +1,3 @@
+// bindgen-flags: --rust-target 1.21
 /// <div rustbindgen replaces="c"></div>
 template <typename a> using b = a;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 int u8;
 int u16;
 int u32;
@@ -0,0 +1,7 @@
+// bindgen-flags: --rust-target 1.77
+
+struct Point {
+  char tag;
+  int x;
+  int y;
+};
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct header
 {
     char proto;
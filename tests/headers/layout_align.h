@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef unsigned char uint8_t;
 typedef unsigned short uint16_t;
 typedef unsigned int uint32_t;
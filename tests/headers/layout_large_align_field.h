@@ -1,4 +1,4 @@
-// bindgen-flags: --rustified-enum .*
+// bindgen-flags: --rust-target 1.21 --rustified-enum .*
 
 typedef unsigned char uint8_t;
 typedef unsigned short uint16_t;
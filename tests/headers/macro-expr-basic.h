@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 #define FOO 1
 #define BAR 4
 #define BAZ (FOO + BAR)
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 
 // Commenting this out & removing the usage before `foo` results in the correct
 // bindings
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 #define FOO 4
 #define BAR (1 + FOO)
 #undef FOO
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 #define foo "bar"
 #define CHAR 'b'
 #define CHARR '\0'
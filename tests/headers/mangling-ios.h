@@ -1,3 +1,3 @@
-// bindgen-flags: -- --target=x86_64-apple-ios
+// bindgen-flags: --rust-target 1.21 -- --target=x86_64-apple-ios
 
 void foo();
@@ -1,4 +1,4 @@
-// bindgen-flags: -- --target=i586-unknown-linux
+// bindgen-flags: --rust-target 1.21 -- --target=i586-unknown-linux
 
 extern "C" void foo();
 
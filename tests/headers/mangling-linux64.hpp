@@ -1,4 +1,4 @@
-// bindgen-flags: -- --target=x86_64-unknown-linux
+// bindgen-flags: --rust-target 1.21 -- --target=x86_64-unknown-linux
 
 extern "C" void foo();
 
@@ -1,4 +1,4 @@
-// bindgen-flags: -- --target=x86_64-apple-darwin
+// bindgen-flags: --rust-target 1.21 -- --target=x86_64-apple-darwin
 
 extern "C" void foo();
 
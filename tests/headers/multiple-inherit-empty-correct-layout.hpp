@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct Foo {};
 struct Bar {};
 struct Baz : public Foo, public Bar {};
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 class C {
     mutable int m_member;
     int m_other;
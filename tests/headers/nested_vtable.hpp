@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 class nsISupports {
 public:
   virtual nsISupports* QueryInterface();
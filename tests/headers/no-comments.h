@@ -1,4 +1,4 @@
-// bindgen-flags: --no-doc-comments
+// bindgen-flags: --rust-target 1.21 --no-doc-comments
 
 struct Foo {
     int s; /*!< Including this will prevent rustc for compiling it */
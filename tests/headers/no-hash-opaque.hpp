@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --opaque-type "NoHash" --no-hash "NoHash"
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --opaque-type "NoHash" --no-hash "NoHash"
 
 class NoHash {
   int i;
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-partialeq --opaque-type "NoPartialEq" --no-partialeq "NoPartialEq"
+// bindgen-flags: --rust-target 1.21 --with-derive-partialeq --opaque-type "NoPartialEq" --no-partialeq "NoPartialEq"
 
 class NoPartialEq {
   int i;
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 
 /** <div rustbindgen nocopy></div> */
 template<typename T>
@@ -1,4 +1,4 @@
-// bindgen-flags: --opaque-type "NoCopy" --no-copy "NoCopy"
+// bindgen-flags: --rust-target 1.21 --opaque-type "NoCopy" --no-copy "NoCopy"
 
 class NoCopy {
   int i;
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type "NoCopy" --no-copy "NoCopy"
+// bindgen-flags: --rust-target 1.21 --whitelist-type "NoCopy" --no-copy "NoCopy"
 
 class NoCopy {
   int i;
@@ -1,4 +1,4 @@
-// bindgen-flags: --objc-extern-crate -- -x objective-c
+// bindgen-flags: --rust-target 1.21 --objc-extern-crate -- -x objective-c
 // bindgen-osx-only
 
 id object;
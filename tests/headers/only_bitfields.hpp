@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 class C {
     bool a: 1;
     bool b: 7;
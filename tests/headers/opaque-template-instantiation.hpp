@@ -1,4 +1,4 @@
-// bindgen-flags: --opaque-type 'Template<int>'  --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --opaque-type 'Template<int>'  --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++14
 
 template <typename T>
 class Template {
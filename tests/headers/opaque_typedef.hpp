@@ -1,4 +1,4 @@
-// bindgen-flags:  --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --with-derive-partialeq --with-derive-eq -- -std=c++11
 template<typename T>
 class RandomTemplate;
 
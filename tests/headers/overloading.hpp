@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 extern bool Evaluate(char r);
 extern bool Evaluate(int x, int y);
 
@@ -0,0 +1,9 @@
+// bindgen-flags: --rust-target 1.21 --owned-wrappers
+
+class Resource {
+  int handle;
+ public:
+  Resource();
+  Resource(int handle);
+  ~Resource();
+};
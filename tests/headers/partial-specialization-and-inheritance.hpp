@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // bindgen-unstable
 
 // This was originally a test case generated by creducing errors in SpiderMonkey
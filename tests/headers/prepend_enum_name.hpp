@@ -1,4 +1,4 @@
-// bindgen-flags: --no-prepend-enum-name
+// bindgen-flags: --rust-target 1.21 --no-prepend-enum-name
 
 enum foo {
 	FOO_BAR,
@@ -0,0 +1,15 @@
+// bindgen-flags: --raw-identifiers --rust-target 1.30
+
+struct foo {
+  int type;
+  int match;
+};
+
+enum kind {
+  type,
+  self,
+};
+
+int match;
+
+void move(int fn);
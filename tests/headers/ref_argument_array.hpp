@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 
 #define NSID_LENGTH 10
 class nsID {
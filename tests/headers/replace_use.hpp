@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template<typename T>
 struct nsTArray {
   int x;
@@ -1,2 +1,4 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef void (FuncType) ();
 extern FuncType Func;
\ No newline at end of file
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces -- -std=c++11
 
 // `Wrapper::sentry` and `sentry` should be emitted as `Wrapper_sentry` and
 // `sentry` respectively, but instead `Wrapper::sentry` is named just `sentry`
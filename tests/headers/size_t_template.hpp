@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template<typename T, unsigned long N>
 class Array {
     T inner[N];
@@ -0,0 +1,17 @@
+// bindgen-flags: --stdint-primitives --whitelist-type Buffer --whitelist-function buffer_len --rust-target 1.77
+
+typedef unsigned int __uint32_t;
+typedef __uint32_t uint32_t;
+typedef long long __int64_t;
+typedef __int64_t int64_t;
+typedef unsigned long size_t;
+typedef long ssize_t;
+
+struct Buffer {
+  uint32_t flags;
+  int64_t offset;
+  size_t len;
+  ssize_t last_read;
+};
+
+size_t buffer_len(const struct Buffer* buffer);
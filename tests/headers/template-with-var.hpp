@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 template<typename T>
 class TemplateWithVar {
     // We shouldn't generate bindings for this because there are potentially
@@ -1,4 +1,4 @@
-// bindgen-flags: -- --target=x86_64-pc-win32
+// bindgen-flags: --rust-target 1.21 -- --target=x86_64-pc-win32
 
 template<typename Method, bool Cancelable>
 struct nsRunnableMethodTraits;
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef void (*foo)(int);
 
 template<typename T, typename U>
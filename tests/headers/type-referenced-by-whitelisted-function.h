@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-function dl_iterate_phdr
+// bindgen-flags: --rust-target 1.21 --whitelist-function dl_iterate_phdr
 
 struct dl_phdr_info {
     int x;
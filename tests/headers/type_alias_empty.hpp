@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type bool_constant -- -std=c++11
+// bindgen-flags: --rust-target 1.21 --whitelist-type bool_constant -- -std=c++11
 
 // NB: The --whitelist-type is done to trigger the traversal of the types on
 // codegen in order to trigger #67.
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type Rooted -- -std=c++14
+// bindgen-flags: --rust-target 1.21 --whitelist-type Rooted -- -std=c++14
 
 template <typename a> using MaybeWrapped = a;
 class Rooted {
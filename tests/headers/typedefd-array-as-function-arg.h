@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // foo.h
 typedef float myVector3[3];
 void modifyVectorFunc(myVector3 v);
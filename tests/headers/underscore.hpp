@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 const int _ = 10;
 
 typedef struct { unsigned char _[8]; } ptr_t;
\ No newline at end of file
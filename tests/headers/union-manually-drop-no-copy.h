@@ -0,0 +1,12 @@
+// bindgen-flags: --rust-target 1.49 --no-derive-copy
+
+struct Point {
+  int x;
+  int y;
+};
+
+union Value {
+  int integer;
+  int *pointer;
+  struct Point point;
+};
@@ -0,0 +1,11 @@
+// bindgen-flags: --rust-target 1.49
+
+struct HasDtor {
+  int member;
+  ~HasDtor();
+};
+
+union UnionWithNonCopyMember {
+  int plain;
+  HasDtor non_copy;
+};
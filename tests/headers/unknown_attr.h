@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 typedef struct {
   long long __clang_max_align_nonce1
       __attribute__((__aligned__(__alignof__(long long))));
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type Baz
+// bindgen-flags: --rust-target 1.21 --whitelist-type Baz
 
 struct Bar {
   const int m_baz;
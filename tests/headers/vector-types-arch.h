@@ -0,0 +1,11 @@
+// bindgen-flags: --rust-target 1.77 -- --target=x86_64-unknown-linux-gnu
+
+typedef float __m128 __attribute__((vector_size(16)));
+typedef int v4si __attribute__((vector_size(16)));
+
+struct Registers {
+  __m128 xmm;
+};
+
+__m128 add_ps(__m128 a, __m128 b);
+v4si add_epi32(v4si a, v4si b);
@@ -0,0 +1,10 @@
+// bindgen-flags: --rust-target 1.77
+
+typedef int v4si __attribute__((vector_size(16)));
+typedef float v8sf __attribute__((vector_size(32)));
+
+struct Samples {
+  v8sf floats;
+  v4si ints;
+  int counts[4];
+};
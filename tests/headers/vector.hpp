@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 struct foo {
   __attribute__((__vector_size__(1 * sizeof(long long)))) long long mMember;
 };
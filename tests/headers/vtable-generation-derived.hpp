@@ -0,0 +1,13 @@
+// bindgen-flags: --rust-target 1.21 --vtable-generation
+
+class Shape {
+public:
+    virtual int sides() const = 0;
+};
+
+// Shares the vtable pointer of `Shape`, so it gets no vtable of its own.
+class Polygon : public Shape {
+public:
+    virtual int sides() const = 0;
+    virtual double area() const = 0;
+};
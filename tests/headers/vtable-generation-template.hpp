@@ -0,0 +1,10 @@
+// bindgen-flags: --rust-target 1.21 --vtable-generation
+
+// The methods of templates aren't kept, so the vtable stays opaque.
+template<class T>
+class Visitor {
+    T value;
+
+public:
+    virtual void visit(T* value) = 0;
+};
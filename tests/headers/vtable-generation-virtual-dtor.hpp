@@ -0,0 +1,8 @@
+// bindgen-flags: --rust-target 1.21 --vtable-generation
+
+// Where the destructors go in the vtable is unknown, so it stays opaque.
+class Resource {
+public:
+    virtual ~Resource();
+    virtual int size() const = 0;
+};
@@ -0,0 +1,8 @@
+// bindgen-flags: --rust-target 1.21 --vtable-generation
+
+class PluginInterface {
+public:
+    virtual int version() const = 0;
+    virtual void process(float* samples, int count) = 0;
+    virtual void process(double* samples, int count) = 0;
+};
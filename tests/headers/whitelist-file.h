@@ -0,0 +1,4 @@
+// bindgen-flags: --rust-target 1.21 --whitelist-file '.*/wanted\.h' -- -Itests/headers/whitelist-file
+
+#include "wanted.h"
+#include "unwanted.h"
@@ -0,0 +1,5 @@
+struct Unwanted {
+    int b;
+};
+
+void unwanted_function(void);
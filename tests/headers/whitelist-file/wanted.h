@@ -0,0 +1,5 @@
+struct Wanted {
+    int a;
+};
+
+void wanted_function(struct Wanted* w);
@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type outer::inner::Helper
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type outer::inner::Helper
 
 namespace outer {
   namespace inner {
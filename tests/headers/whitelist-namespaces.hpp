@@ -1,4 +1,4 @@
-// bindgen-flags: --enable-cxx-namespaces --whitelist-type '.*'
+// bindgen-flags: --rust-target 1.21 --enable-cxx-namespaces --whitelist-type '.*'
 
 namespace outer {
   namespace inner {
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type WhitelistMe
+// bindgen-flags: --rust-target 1.21 --whitelist-type WhitelistMe
 
 template<typename T>
 class WhitelistMe {
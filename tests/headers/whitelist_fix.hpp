@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-function 'Servo_.*' --blacklist-type Test --raw-line "pub enum Test {}"
+// bindgen-flags: --rust-target 1.21 --whitelist-function 'Servo_.*' --blacklist-type Test --raw-line "pub enum Test {}"
 
 struct Test {};
 extern "C" void Servo_Test(Test* a);
@@ -1,3 +1,5 @@
+// bindgen-flags: --rust-target 1.21
+
 #define NONE      0
 #define FOO       5
 #define FOOB     -2
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-hash --whitelist-type "WhitelistMe" --no-hash "NoHash"
+// bindgen-flags: --rust-target 1.21 --with-derive-hash --whitelist-type "WhitelistMe" --no-hash "NoHash"
 
 struct NoHash {};
 
@@ -1,4 +1,4 @@
-// bindgen-flags: --with-derive-partialeq --whitelist-type "WhitelistMe" --no-partialeq "NoPartialEq"
+// bindgen-flags: --rust-target 1.21 --with-derive-partialeq --whitelist-type "WhitelistMe" --no-partialeq "NoPartialEq"
 
 struct NoPartialEq {};
 
@@ -1,4 +1,4 @@
-// bindgen-flags: --whitelist-type "WhitelistMe" --no-copy "NoCopy"
+// bindgen-flags: --rust-target 1.21 --whitelist-type "WhitelistMe" --no-copy "NoCopy"
 
 struct NoCopy {};
 
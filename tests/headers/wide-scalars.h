@@ -0,0 +1,12 @@
+// bindgen-flags: --rust-target 1.77 -- --target=x86_64-unknown-linux
+
+struct WideScalars {
+  __int128 big;
+  unsigned __int128 ubig;
+  long double extended;
+  // `__fp16` is storage-only, but unlike `_Float16` every libclang takes it.
+  __fp16 halves[8];
+};
+
+__int128 widen(long long value);
+long double extend(double value);
@@ -0,0 +1,21 @@
+// bindgen-flags: --rust-target 1.21 --wrap-static-fns
+
+static inline int foo() {
+    return 11;
+}
+static int bar() {
+    return 1;
+}
+inline int baz() {
+    return 2;
+}
+static inline int takes_ptr(int* arg) {
+    return *arg + 1;
+}
+static inline int takes_fn_ptr(int (*f)(int)) {
+    return f(1);
+}
+static inline void nevermore() {}
+static inline int variadic(int x, ...) {
+    return x;
+}
@@ -1,3 +1,4 @@
+// bindgen-flags: --rust-target 1.21
 // These classes are technically zero-sized, but despite that they still don't
 // get an `_address` field inserted.
 
@@ -3,7 +3,7 @@ extern crate diff;
 extern crate bindgen;
 extern crate shlex;
 
-use bindgen::{Builder, builder, clang_version};
+use bindgen::{BindgenError, Builder, builder, clang_version};
 use std::fs;
 use std::io::{self, BufRead, BufReader, Error, ErrorKind, Read, Write};
 use std::path::PathBuf;
@@ -159,7 +159,7 @@ fn compare_generated_header(
             let actual = bindings.to_string();
             rustfmt(actual)
         }
-        Err(()) => ("<error generating bindings>".to_string(), "".to_string()),
+        Err(_) => ("<error generating bindings>".to_string(), "".to_string()),
     };
     println!("{}", rustfmt_stderr);
 
@@ -239,6 +239,21 @@ fn create_bindgen_builder(header: &PathBuf) -> Result<Option<Builder>, Error> {
         }
     }
 
+    // The static function wrappers need somewhere to go outside of a build
+    // script. Keep them out of the way, one file per header.
+    if flags.iter().any(|flag| flag == "--wrap-static-fns") &&
+        !flags.iter().any(|flag| flag == "--wrap-static-fns-path")
+    {
+        let stem = header.file_stem().unwrap().to_string_lossy();
+        let path = std::env::temp_dir().join(format!(
+            "bindgen-test-{}-{}.c",
+            stem,
+            process::id()
+        ));
+        flags.insert(0, path.display().to_string());
+        flags.insert(0, "--wrap-static-fns-path".into());
+    }
+
     // Different platforms have various different conventions like struct padding, mangling, etc.
     // We make the default target as x86_64-unknown-linux
     if flags.iter().all(|flag| !flag.starts_with("--target=")) {
@@ -319,7 +334,7 @@ fn test_header_contents() {
 
     let (expected, _) = rustfmt("/* automatically generated by rust-bindgen */
 
-extern \"C\" {
+unsafe extern \"C\" {
     pub fn foo(a: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
 }
 ".to_string());
@@ -419,3 +434,472 @@ fn dump_preprocessed_input() {
         "cpp-empty-layout.hpp is in the preprocessed file"
     );
 }
+
+#[test]
+fn test_include_file_callback() {
+    use bindgen::callbacks::ParseCallbacks;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct IncludedFiles(Arc<Mutex<Vec<String>>>);
+
+    impl ParseCallbacks for IncludedFiles {
+        fn include_file(&self, filename: &str) {
+            self.0.lock().unwrap().push(filename.to_owned());
+        }
+    }
+
+    let included = Arc::new(Mutex::new(vec![]));
+    let header =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/headers/whitelist-file.h");
+
+    builder()
+        .header(header)
+        .clang_arg(concat!(
+            "-I",
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/headers/whitelist-file"
+        ))
+        .parse_callbacks(Box::new(IncludedFiles(included.clone())))
+        .generate()
+        .expect("should generate bindings");
+
+    let included = included.lock().unwrap();
+    assert_eq!(included[0], header);
+    assert!(included[1].ends_with("/wanted.h"));
+    assert!(included[2].ends_with("/unwanted.h"));
+    assert_eq!(included.len(), 3);
+}
+
+#[test]
+fn test_item_name_callback() {
+    use bindgen::callbacks::{ItemKind, ParseCallbacks};
+
+    #[derive(Debug)]
+    struct StripPrefix;
+
+    impl ParseCallbacks for StripPrefix {
+        fn item_name(&self, name: &str, kind: ItemKind) -> Option<String> {
+            let name = name.trim_start_matches("foo_lib_");
+            Some(match kind {
+                ItemKind::Type => match name {
+                    "context_t" => "Context".to_owned(),
+                    _ => name.to_owned(),
+                },
+                _ => name.to_owned(),
+            })
+        }
+    }
+
+    let actual = builder()
+        .header_contents(
+            "test.h",
+            "typedef struct foo_lib_context { int x; } foo_lib_context_t;
+             void foo_lib_init(foo_lib_context_t* ctx);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .layout_tests(false)
+        .parse_callbacks(Box::new(StripPrefix))
+        .generate()
+        .unwrap()
+        .to_string();
+
+    let (actual, stderr) = rustfmt(actual);
+    println!("{}", stderr);
+
+    let (expected, _) = rustfmt("/* automatically generated by rust-bindgen */
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct context {
+    pub x: ::std::os::raw::c_int,
+}
+pub type Context = context;
+extern \"C\" {
+    #[link_name = \"\\u{1}foo_lib_init\"]
+    pub fn init(ctx: *mut Context);
+}
+".to_string());
+
+    assert_eq!(
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_macro_callbacks() {
+    use bindgen::callbacks::ParseCallbacks;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Macros(Arc<Mutex<Vec<String>>>);
+
+    impl ParseCallbacks for Macros {
+        fn str_macro(&self, name: &str, value: &[u8]) {
+            self.0.lock().unwrap().push(
+                format!("{} = {:?}", name, String::from_utf8_lossy(value)),
+            );
+        }
+
+        fn func_macro(&self, name: &str, params: &[&str], tokens: &[&str]) {
+            self.0.lock().unwrap().push(
+                format!("{}({}) {}", name, params.join(", "), tokens.join(" ")),
+            );
+        }
+    }
+
+    let macros = Arc::new(Mutex::new(vec![]));
+
+    builder()
+        .header_contents(
+            "test.h",
+            "#define VERSION \"1.2\"
+             #define ADD(a, b) ((a) + (b))
+             #define LOG(fmt, ...) printf(fmt, __VA_ARGS__)
+             #define ANSWER 42",
+        )
+        .parse_callbacks(Box::new(Macros(macros.clone())))
+        .generate()
+        .expect("should generate bindings");
+
+    assert_eq!(
+        *macros.lock().unwrap(),
+        vec![
+            "VERSION = \"1.2\"".to_owned(),
+            "ADD(a, b) ( ( a ) + ( b ) )".to_owned(),
+            "LOG(fmt, ...) printf ( fmt , __VA_ARGS__ )".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn test_diagnostics() {
+    use bindgen::DiagnosticSeverity;
+
+    let bindings = builder()
+        .header_contents("test.h", "#warning careful\nint foo(void);")
+        .generate()
+        .expect("warnings shouldn't fail generation");
+
+    let diagnostics = bindings.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].file, Some(PathBuf::from("test.h")));
+    assert_eq!((diagnostics[0].line, diagnostics[0].column), (1, 2));
+    assert_eq!(diagnostics[0].message, "careful");
+}
+
+#[test]
+fn test_diagnostic_callback() {
+    use bindgen::Diagnostic;
+    use bindgen::callbacks::ParseCallbacks;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug)]
+    struct Diagnostics(Arc<Mutex<Vec<Diagnostic>>>);
+
+    impl ParseCallbacks for Diagnostics {
+        fn diagnostic(&self, diagnostic: &Diagnostic) {
+            self.0.lock().unwrap().push(diagnostic.clone());
+        }
+    }
+
+    let reported = Arc::new(Mutex::new(vec![]));
+
+    let bindings = builder()
+        .header_contents("test.h", "#warning careful\n#warning again\n")
+        .parse_callbacks(Box::new(Diagnostics(reported.clone())))
+        .generate()
+        .expect("warnings shouldn't fail generation");
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(&*reported, bindings.diagnostics());
+    let messages = reported.iter().map(|d| &*d.message).collect::<Vec<_>>();
+    assert_eq!(messages, vec!["careful", "again"]);
+}
+
+#[test]
+fn test_fail_on_clang_errors() {
+    use bindgen::{BindgenError, DiagnosticSeverity};
+
+    let contents = "#warning careful\n#error broken\nint foo(void);";
+
+    match builder().header_contents("test.h", contents).generate() {
+        Err(BindgenError::ClangDiagnostics(diagnostics)) => {
+            let severities =
+                diagnostics.iter().map(|d| d.severity).collect::<Vec<_>>();
+            assert_eq!(
+                severities,
+                vec![DiagnosticSeverity::Warning, DiagnosticSeverity::Error]
+            );
+            assert_eq!(diagnostics[1].message, "broken");
+        }
+        other => panic!("expected a clang error, got {:?}", other),
+    }
+
+    let bindings = builder()
+        .header_contents("test.h", contents)
+        .fail_on_clang_errors(false)
+        .generate()
+        .expect("errors shouldn't fail generation when allowed");
+    assert!(bindings.diagnostics().iter().any(|d| d.is_error()));
+    assert!(bindings.to_string().contains("pub fn foo()"));
+}
+
+#[test]
+fn test_missing_header() {
+    let header = std::env::temp_dir()
+        .join(format!("bindgen-test-missing-header-{}.h", process::id()));
+
+    match builder().header(header.to_str().unwrap()).generate() {
+        Err(BindgenError::NotExist(ref path)) => assert_eq!(path, &header),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("should fail on a missing header"),
+    }
+}
+
+#[test]
+fn test_folder_as_header() {
+    let dir = std::env::temp_dir();
+
+    match builder().header(dir.to_str().unwrap()).generate() {
+        Err(BindgenError::FolderAsHeader(ref path)) => assert_eq!(path, &dir),
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("should fail on a folder"),
+    }
+}
+
+#[test]
+fn test_invalid_regex() {
+    let result = builder()
+        .header_contents("test.h", "struct foo { char a; };")
+        .whitelist_type("(")
+        .generate();
+    match result {
+        Err(BindgenError::InvalidRegex(_)) => {}
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("should fail on an invalid regex"),
+    }
+}
+
+#[test]
+fn test_wrap_static_fns() {
+    let dir = std::env::temp_dir()
+        .join(format!("bindgen-test-wrap-static-fns-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let header = dir.join("test.h");
+    let path = dir.join("extern.c");
+    fs::File::create(&header)
+        .unwrap()
+        .write_all(
+            b"static inline int foo(void) { return 11; }\n\
+              static inline int takes_ptr(int *arg) { return *arg + 1; }\n\
+              static inline void nevermore(void) {}\n\
+              static inline int variadic(int x, ...) { return x; }\n\
+              int exported(void);\n",
+        )
+        .unwrap();
+
+    builder()
+        .header(header.to_str().unwrap())
+        .wrap_static_fns(true)
+        .wrap_static_fns_path(&path)
+        .generate()
+        .expect("should generate bindings");
+
+    let mut actual = String::new();
+    fs::File::open(&path)
+        .expect("should write the static wrappers")
+        .read_to_string(&mut actual)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let expected = format!(
+        "#include \"{}\"
+
+// Static wrappers
+
+int foo__extern(void) {{ return foo(); }}
+int takes_ptr__extern(int * arg_1) {{ return takes_ptr(arg_1); }}
+void nevermore__extern(void) {{ nevermore(); }}
+",
+        header.display()
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_wrap_static_fns_without_path() {
+    if std::env::var_os("OUT_DIR").is_some() {
+        return;
+    }
+
+    let result = builder()
+        .header_contents("test.h", "static inline int foo(void) { return 1; }")
+        .wrap_static_fns(true)
+        .generate();
+    match result {
+        Err(BindgenError::NoStaticWrappersPath) => {}
+        Err(e) => panic!("unexpected error: {}", e),
+        Ok(_) => panic!("should fail without a path"),
+    }
+}
+
+#[test]
+fn test_wrap_static_fns_header_contents() {
+    let dir = std::env::temp_dir().join(format!(
+        "bindgen-test-wrap-static-fns-header-contents-{}",
+        process::id()
+    ));
+    let path = dir.join("extern.c");
+
+    builder()
+        .header_contents("test.h", "static inline int foo(void) { return 11; }")
+        .wrap_static_fns(true)
+        .wrap_static_fns_path(&path)
+        .generate()
+        .expect("should generate bindings");
+
+    let mut actual = String::new();
+    fs::File::open(&path)
+        .expect("should write the static wrappers")
+        .read_to_string(&mut actual)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let expected = "#line 1 \"test.h\"
+static inline int foo(void) { return 11; }
+
+// Static wrappers
+
+int foo__extern(void) { return foo(); }
+";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_depfile() {
+    let dir = std::env::temp_dir()
+        .join(format!("bindgen-test-depfile-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let header = dir.join("test.h");
+    let included = dir.join("included.h");
+    let depfile = dir.join("bindings.d");
+    fs::File::create(&header)
+        .unwrap()
+        .write_all(b"#include \"included.h\"\n")
+        .unwrap();
+    fs::File::create(&included)
+        .unwrap()
+        .write_all(b"struct foo { int x; };\n")
+        .unwrap();
+
+    builder()
+        .header(header.to_str().unwrap())
+        .depfile("bindings.rs", &depfile)
+        .generate()
+        .expect("should generate bindings");
+
+    let mut actual = String::new();
+    fs::File::open(&depfile)
+        .expect("should write the depfile")
+        .read_to_string(&mut actual)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        actual,
+        format!("bindings.rs: {} {}\n", included.display(), header.display())
+    );
+}
+
+#[test]
+fn test_c_layout_assertions() {
+    let dir = std::env::temp_dir()
+        .join(format!("bindgen-test-c-layout-assertions-{}", process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let header = dir.join("test.h");
+    let path = dir.join("layout.c");
+    fs::File::create(&header)
+        .unwrap()
+        .write_all(
+            b"struct foo { char a; int b; };\n\
+              typedef struct { short x; } bar;\n\
+              struct { int y; } baz;\n",
+        )
+        .unwrap();
+
+    builder()
+        .header(header.to_str().unwrap())
+        .clang_arg("--target=x86_64-unknown-linux")
+        .c_layout_assertions(&path)
+        .generate()
+        .expect("should generate bindings");
+
+    let mut actual = String::new();
+    fs::File::open(&path)
+        .expect("should write the layout assertions")
+        .read_to_string(&mut actual)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let expected = format!(
+        "#include <stddef.h>
+#include \"{}\"
+
+_Static_assert(sizeof(struct foo) == 8, \"Size of struct foo\");
+_Static_assert(_Alignof(struct foo) == 4, \"Alignment of struct foo\");
+_Static_assert(offsetof(struct foo, a) == 0, \"Offset of field a in struct foo\");
+_Static_assert(offsetof(struct foo, b) == 4, \"Offset of field b in struct foo\");
+
+_Static_assert(sizeof(bar) == 2, \"Size of bar\");
+_Static_assert(_Alignof(bar) == 2, \"Alignment of bar\");
+_Static_assert(offsetof(bar, x) == 0, \"Offset of field x in bar\");
+",
+        header.display()
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_c_layout_assertions_header_contents() {
+    let dir = std::env::temp_dir().join(format!(
+        "bindgen-test-c-layout-assertions-header-contents-{}",
+        process::id()
+    ));
+    let path = dir.join("layout.c");
+
+    builder()
+        .header_contents("test.h", "struct foo { char a; };")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .c_layout_assertions(&path)
+        .generate()
+        .expect("should generate bindings");
+
+    let mut actual = String::new();
+    fs::File::open(&path)
+        .expect("should write the layout assertions")
+        .read_to_string(&mut actual)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let expected = "#include <stddef.h>
+#line 1 \"test.h\"
+struct foo { char a; };
+
+_Static_assert(sizeof(struct foo) == 1, \"Size of struct foo\");
+_Static_assert(_Alignof(struct foo) == 1, \"Alignment of struct foo\");
+_Static_assert(offsetof(struct foo, a) == 0, \"Offset of field a in struct foo\");
+";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_c_layout_assertions_cpp() {
+    let result = builder()
+        .header_contents("test.hpp", "struct foo { char a; };")
+        .c_layout_assertions("layout.c")
+        .generate();
+    assert!(result.is_err());
+}